@@ -0,0 +1,111 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where to read a day's puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own `input.txt`.
+    Default,
+    /// Any file on disk.
+    File(PathBuf),
+    /// Everything on standard input.
+    Stdin,
+    /// A file next to the day's `input.txt`, such as `input_test_2.txt`.
+    Fixture(String),
+}
+
+impl InputSource {
+    /// Parses the value of `--fixture`, which may leave off the `.txt` extension.
+    pub fn fixture(name: &str) -> Self {
+        if name.ends_with(".txt") {
+            InputSource::Fixture(name.to_string())
+        } else {
+            InputSource::Fixture(format!("{name}.txt"))
+        }
+    }
+
+    /// Parses the value of `--input`, where `-` stands for standard input.
+    pub fn file(path: &str) -> Self {
+        if path == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(path))
+        }
+    }
+
+    pub fn is_shared_by_all_days(&self) -> bool {
+        matches!(self, InputSource::Default | InputSource::Fixture(_))
+    }
+
+    /// The file this source reads for a day stored in `day_dir`, if any.
+    pub fn path(&self, day_dir: &Path) -> Option<PathBuf> {
+        match self {
+            InputSource::Default => Some(day_dir.join("input.txt")),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+            InputSource::Fixture(name) => Some(day_dir.join(name)),
+        }
+    }
+
+    pub fn read(&self, day_dir: &Path) -> io::Result<String> {
+        match self.path(day_dir) {
+            Some(path) => fs::read_to_string(&path)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display()))),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixture_name() {
+        assert_eq!(
+            InputSource::fixture("input_test_2"),
+            InputSource::Fixture("input_test_2.txt".to_string())
+        );
+        assert_eq!(
+            InputSource::fixture("input_test_2.txt"),
+            InputSource::Fixture("input_test_2.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn test_file_dash_is_stdin() {
+        assert_eq!(InputSource::file("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::file("other.txt"),
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        let day_dir = Path::new("day6");
+        assert_eq!(
+            InputSource::Default.path(day_dir),
+            Some(PathBuf::from("day6/input.txt"))
+        );
+        assert_eq!(
+            InputSource::fixture("input_test_3").path(day_dir),
+            Some(PathBuf::from("day6/input_test_3.txt"))
+        );
+        assert_eq!(InputSource::Stdin.path(day_dir), None);
+    }
+
+    #[test]
+    fn test_read_fixture() {
+        let day_dir: PathBuf = [env!("CARGO_MANIFEST_DIR"), "..", "day6"].iter().collect();
+        let input = InputSource::fixture("input_test_1").read(&day_dir).unwrap();
+        assert_eq!(input, "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+    }
+}
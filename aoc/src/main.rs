@@ -0,0 +1,286 @@
+use std::{
+    env,
+    error::Error,
+    path::{Path, PathBuf},
+    process,
+    time::Instant,
+};
+
+use common::Answer;
+use input::InputSource;
+use registry::Day;
+
+mod input;
+mod registry;
+
+const USAGE: &str = "Usage: aoc run --day <day> [--part <1|2>] [--input <path> | --stdin | --fixture <name>]
+       aoc run --all [--part <1|2>] [--fixture <name>]
+
+Options:
+  --threads <n>     evaluate day 19 blueprints on n threads, or one per CPU if 0
+
+Input:
+  --input <path>    read the puzzle input from a file, or from stdin if <path> is -
+  --stdin           read the puzzle input from stdin
+  --fixture <name>  read a file next to the day's input.txt, e.g. input_test_2.txt";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    selection: Selection,
+    part: Option<u8>,
+    input: InputSource,
+    threads: usize,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter().map(String::as_str);
+    match args.next() {
+        Some("run") => (),
+        Some(command) => return Err(format!("Unknown command: {command}")),
+        None => return Err("Missing command".to_string()),
+    }
+
+    let mut selection = None;
+    let mut part = None;
+    let mut input = InputSource::Default;
+    let mut threads = 0;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--all" => selection = Some(Selection::All),
+            "--day" => {
+                let day = args.next().ok_or("Missing value for --day")?;
+                let day = day.parse().map_err(|_| format!("Invalid day: {day}"))?;
+                selection = Some(Selection::Day(day));
+            }
+            "--part" => {
+                let value = args.next().ok_or("Missing value for --part")?;
+                match value.parse() {
+                    Ok(p @ (1 | 2)) => part = Some(p),
+                    _ => return Err(format!("Invalid part: {value}")),
+                }
+            }
+            "--threads" => {
+                let value = args.next().ok_or("Missing value for --threads")?;
+                threads = value
+                    .parse()
+                    .map_err(|_| format!("Invalid thread count: {value}"))?;
+            }
+            "--input" => {
+                let path = args.next().ok_or("Missing value for --input")?;
+                input = InputSource::file(path);
+            }
+            "--stdin" => input = InputSource::Stdin,
+            "--fixture" => {
+                let name = args.next().ok_or("Missing value for --fixture")?;
+                input = InputSource::fixture(name);
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+
+    let selection = selection.ok_or("Either --day or --all is required")?;
+    if selection == Selection::All && !input.is_shared_by_all_days() {
+        return Err("--input and --stdin can only be used with --day".to_string());
+    }
+    Ok(Args {
+        selection,
+        part,
+        input,
+        threads,
+    })
+}
+
+/// With `--all`, days whose own `input.txt` has not been added yet are
+/// skipped rather than failing the run. Returns the missing file.
+fn missing_default_input(args: &Args, day_dir: &Path) -> Option<PathBuf> {
+    if args.selection != Selection::All || args.input != InputSource::Default {
+        return None;
+    }
+    args.input.path(day_dir).filter(|path| !path.exists())
+}
+
+fn run_day(day: &Day, parts: &[u8], source: &InputSource) -> Result<(), Box<dyn Error>> {
+    let input = source.read(&day.dir())?;
+
+    let start_parsing = Instant::now();
+    let parsed = day.solution.parse_dyn(&input).map_err(|err| {
+        let file = source
+            .path(&day.dir())
+            .map_or("<stdin>".to_string(), |path| path.display().to_string());
+        err.with_file(file)
+    })?;
+    let elapsed_time_parsing = start_parsing.elapsed().as_micros();
+    println!("Day {} parsed in {elapsed_time_parsing} µs", day.day);
+
+    for &part in parts {
+        let start = Instant::now();
+        let result = if part == 1 {
+            day.solution.part1_dyn(parsed.as_ref())
+        } else {
+            day.solution.part2_dyn(parsed.as_ref())
+        };
+        let elapsed_time = start.elapsed().as_micros();
+        let result = match result
+            .map_err(|err| format!("part {part} failed after {elapsed_time} µs: {err}"))?
+        {
+            Answer::Detailed { answer, details } => {
+                for line in details {
+                    println!("{line}");
+                }
+                answer.to_string()
+            }
+            answer => answer.to_string(),
+        };
+        if result.contains('\n') {
+            println!(
+                "Day {} part {part}: in {elapsed_time} µs\n{result}",
+                day.day
+            );
+        } else {
+            println!("Day {} part {part}: {result} in {elapsed_time} µs", day.day);
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = parse_args(&args).unwrap_or_else(|err| {
+        eprintln!("{err}\n\n{USAGE}");
+        process::exit(2);
+    });
+
+    let days = registry::days(args.threads);
+    let selected: Vec<&Day> = match args.selection {
+        Selection::All => days.iter().collect(),
+        Selection::Day(day) => days.iter().filter(|d| d.day == day).collect(),
+    };
+    if selected.is_empty() {
+        eprintln!("No solution registered for the selected day");
+        process::exit(1);
+    }
+
+    let parts = args.part.map_or(vec![1, 2], |p| vec![p]);
+    let mut failed = false;
+    for day in selected {
+        if let Some(path) = missing_default_input(&args, &day.dir()) {
+            eprintln!(
+                "Day {}: skipped, {} does not exist",
+                day.day,
+                path.display()
+            );
+            continue;
+        }
+        if let Err(err) = run_day(day, &parts, &args.input) {
+            eprintln!("Day {}: {err}", day.day);
+            failed = true;
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args_day_and_part() {
+        assert_eq!(
+            parse_args(&args("run --day 14 --part 2")),
+            Ok(Args {
+                selection: Selection::Day(14),
+                part: Some(2),
+                input: InputSource::Default,
+                threads: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_all() {
+        assert_eq!(
+            parse_args(&args("run --all")),
+            Ok(Args {
+                selection: Selection::All,
+                part: None,
+                input: InputSource::Default,
+                threads: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_input() {
+        assert_eq!(
+            parse_args(&args("run --day 9 --fixture input_test_2")),
+            Ok(Args {
+                selection: Selection::Day(9),
+                part: None,
+                input: InputSource::Fixture("input_test_2.txt".to_string()),
+                threads: 0,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run --day 9 --input -")).map(|a| a.input),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(
+            parse_args(&args("run --day 9 --input teammate.txt")).map(|a| a.input),
+            Ok(InputSource::File("teammate.txt".into()))
+        );
+        assert!(parse_args(&args("run --all --stdin")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_threads() {
+        assert_eq!(
+            parse_args(&args("run --day 19 --threads 4")).map(|a| a.threads),
+            Ok(4)
+        );
+        assert!(parse_args(&args("run --day 19 --threads many")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_invalid() {
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run --day 3 --part 3")).is_err());
+        assert!(parse_args(&args("build --all")).is_err());
+    }
+
+    #[test]
+    fn test_missing_default_input() {
+        let dir = Path::new("no-such-day");
+        let all = parse_args(&args("run --all")).unwrap();
+        assert_eq!(
+            missing_default_input(&all, dir),
+            Some(dir.join("input.txt"))
+        );
+        let day = parse_args(&args("run --day 16")).unwrap();
+        assert_eq!(missing_default_input(&day, dir), None);
+        let fixture = parse_args(&args("run --all --fixture input_test")).unwrap();
+        assert_eq!(missing_default_input(&fixture, dir), None);
+        let present = registry::days(0)[0].dir();
+        assert_eq!(missing_default_input(&all, &present), None);
+    }
+
+    #[test]
+    fn test_registry_days_are_unique() {
+        let days = registry::days(0);
+        let mut numbers: Vec<_> = days.iter().map(|d| d.day).collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+        assert_eq!(numbers.len(), days.len());
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+
+use common::DynSolution;
+
+pub struct Day {
+    pub day: u8,
+    pub solution: Box<dyn DynSolution>,
+}
+
+impl Day {
+    /// The crate directory holding the day's `input.txt` and test fixtures.
+    pub fn dir(&self) -> PathBuf {
+        [
+            env!("CARGO_MANIFEST_DIR"),
+            "..",
+            &format!("day{}", self.day),
+        ]
+        .iter()
+        .collect()
+    }
+}
+
+/// Every day with a solution, where day 19 evaluates blueprints on `threads`
+/// threads, or on one per CPU if it is 0.
+pub fn days(threads: usize) -> Vec<Day> {
+    vec![
+        Day {
+            day: 1,
+            solution: Box::new(day1::Day1),
+        },
+        Day {
+            day: 2,
+            solution: Box::new(day2::Day2),
+        },
+        Day {
+            day: 3,
+            solution: Box::new(day3::Day3),
+        },
+        Day {
+            day: 4,
+            solution: Box::new(day4::Day4),
+        },
+        Day {
+            day: 5,
+            solution: Box::new(day5::Day5),
+        },
+        Day {
+            day: 6,
+            solution: Box::new(day6::Day6),
+        },
+        Day {
+            day: 7,
+            solution: Box::new(day7::Day7),
+        },
+        Day {
+            day: 8,
+            solution: Box::new(day8::Day8),
+        },
+        Day {
+            day: 9,
+            solution: Box::new(day9::Day9),
+        },
+        Day {
+            day: 10,
+            solution: Box::new(day10::Day10),
+        },
+        Day {
+            day: 11,
+            solution: Box::new(day11::Day11),
+        },
+        Day {
+            day: 12,
+            solution: Box::new(day12::Day12),
+        },
+        Day {
+            day: 13,
+            solution: Box::new(day13::Day13),
+        },
+        Day {
+            day: 14,
+            solution: Box::new(day14::Day14),
+        },
+        Day {
+            day: 15,
+            solution: Box::new(day15::Day15 {
+                row: 2000000,
+                max_loc: 4000000,
+            }),
+        },
+        Day {
+            day: 16,
+            solution: Box::new(day16::Day16),
+        },
+        Day {
+            day: 17,
+            solution: Box::new(day17::Day17),
+        },
+        Day {
+            day: 18,
+            solution: Box::new(day18::Day18),
+        },
+        Day {
+            day: 19,
+            solution: Box::new(day19::Day19 {
+                threads,
+                objective: day19::GEODE,
+            }),
+        },
+        Day {
+            day: 20,
+            solution: Box::new(day20::Day20),
+        },
+        Day {
+            day: 21,
+            solution: Box::new(day21::Day21),
+        },
+        Day {
+            day: 22,
+            solution: Box::new(day22::Day22),
+        },
+    ]
+}
@@ -0,0 +1,119 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Puzzle input that could not be parsed, with the location of the offending text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for `span`, which should be a slice of `base`. The line
+    /// and column are those of `span` within `base`.
+    pub fn at(base: &str, span: &str, message: impl Into<String>) -> Self {
+        let (line, column) = match offset_in(base, span) {
+            Some(offset) => {
+                let before = &base[..offset];
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                (
+                    before.matches('\n').count() + 1,
+                    before[line_start..].chars().count() + 1,
+                )
+            }
+            None => (1, 1),
+        };
+        Self {
+            file: None,
+            line,
+            column,
+            text: span.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Moves the error down by `lines`, for errors raised on a slice of the
+    /// input that does not start at its first line.
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+fn offset_in(base: &str, span: &str) -> Option<usize> {
+    let base_start = base.as_ptr() as usize;
+    let span_start = span.as_ptr() as usize;
+    (span_start >= base_start && span_start + span.len() <= base_start + base.len())
+        .then_some(span_start - base_start)
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}: '{}'",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            self.message,
+            self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line of `input` with `parse_line`, which reports errors
+/// relative to the line it was given.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(ix, line)| parse_line(line).map_err(|err| err.shift_lines(ix)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "1,2\n3,x\n";
+        let err = ParseError::at(input, &input[6..7], "Invalid number");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "x");
+        assert_eq!(err.to_string(), "<input>:2:3: Invalid number: 'x'");
+    }
+
+    #[test]
+    fn test_at_span_outside_base() {
+        let err = ParseError::at("abc", "xyz", "Unexpected");
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let result = parse_lines("1\n2\nthree\n4", |line| {
+            line.parse::<u32>()
+                .map_err(|_| ParseError::at(line, line, "Invalid number"))
+        });
+        let err = result.unwrap_err().with_file("numbers.txt");
+        assert_eq!(err.to_string(), "numbers.txt:3:1: Invalid number: 'three'");
+    }
+}
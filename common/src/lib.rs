@@ -0,0 +1,6 @@
+mod error;
+pub mod search;
+mod solution;
+
+pub use error::{parse_lines, ParseError};
+pub use solution::{Answer, DynSolution, PartResult, Solution};
@@ -0,0 +1,278 @@
+//! Graph searches over a neighbour function, for puzzles whose graph is
+//! implicit in a grid or a set of positions.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Outcome of a search: every node it reached, with its cost from the
+/// nearest start and the node it was reached from.
+#[derive(Clone, Debug)]
+pub struct Search<N, C> {
+    visited: HashMap<N, (C, Option<N>)>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    /// The first goal node found, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The cost from the nearest start to the goal.
+    pub fn cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.cost_to(goal))
+    }
+
+    /// The nodes from a start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+
+    pub fn cost_to(&self, node: &N) -> Option<C> {
+        self.visited.get(node).map(|(cost, _)| *cost)
+    }
+
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut from = &self.visited.get(node)?.1;
+        while let Some(node) = from {
+            path.push(node.clone());
+            from = &self.visited[node].1;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn is_visited(&self, node: &N) -> bool {
+        self.visited.contains_key(node)
+    }
+
+    pub fn visited(&self) -> impl Iterator<Item = &N> {
+        self.visited.keys()
+    }
+}
+
+/// Breadth-first search where every step costs 1. The search stops at the
+/// first node that satisfies `is_goal`; with a goal that never matches it
+/// visits everything reachable from `starts`.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !visited.contains_key(&start) {
+            visited.insert(start.clone(), (0, None));
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Search {
+                visited,
+                goal: Some(node),
+            };
+        }
+        let cost = visited[&node].0 + 1;
+        for next in neighbours(&node) {
+            if !visited.contains_key(&next) {
+                visited.insert(next.clone(), (cost, Some(node.clone())));
+                queue.push_back(next);
+            }
+        }
+    }
+    Search {
+        visited,
+        goal: None,
+    }
+}
+
+/// Cheapest-first search where `neighbours` yields each neighbour with the
+/// cost of the step to it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, which must never overestimate the
+/// remaining cost to a goal and must be consistent across steps.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited: HashMap<N, (C, Option<N>)> = HashMap::new();
+    let mut best: HashMap<N, C> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        best.insert(start.clone(), C::default());
+        queue.push(Entry {
+            estimate: heuristic(&start),
+            cost: C::default(),
+            node: start,
+            from: None,
+        });
+    }
+
+    while let Some(Entry {
+        cost, node, from, ..
+    }) = queue.pop()
+    {
+        if visited.contains_key(&node) {
+            continue;
+        }
+        visited.insert(node.clone(), (cost, from));
+        if is_goal(&node) {
+            return Search {
+                visited,
+                goal: Some(node),
+            };
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if visited.contains_key(&next) || best.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            queue.push(Entry {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+                from: Some(node.clone()),
+            });
+        }
+    }
+    Search {
+        visited,
+        goal: None,
+    }
+}
+
+/// Queue entry ordered so that the lowest estimate pops first from a max-heap.
+struct Entry<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+    from: Option<N>,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 - 1 - 2
+    // |       |
+    // 3 ----- 4 - 5     6
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 1)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (4, 1)],
+            3 => vec![(0, 1), (4, 5)],
+            4 => vec![(2, 1), (3, 5), (5, 1)],
+            5 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &u32) -> Vec<u32> {
+        edges(node).into_iter().map(|(n, _)| n).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([0], unweighted, |&n| n == 5);
+        assert_eq!(search.cost(), Some(3));
+        assert_eq!(search.path().unwrap().len(), 4);
+        assert_eq!(search.path().unwrap().first(), Some(&0));
+        assert!(!search.is_visited(&6));
+    }
+
+    #[test]
+    fn test_bfs_multiple_starts() {
+        let search = bfs([0, 5], unweighted, |&n| n == 4);
+        assert_eq!(search.path(), Some(vec![5, 4]));
+    }
+
+    #[test]
+    fn test_bfs_flood_fill() {
+        let search = bfs([0], unweighted, |_| false);
+        assert_eq!(search.goal(), None);
+        let mut visited: Vec<_> = search.visited().copied().collect();
+        visited.sort();
+        assert_eq!(visited, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(search.cost_to(&4), Some(2));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra([3], edges, |&n| n == 5);
+        assert_eq!(search.cost(), Some(5));
+        assert_eq!(search.path(), Some(vec![3, 0, 1, 2, 4, 5]));
+        assert_eq!(dijkstra([3], edges, |&n| n == 6).path(), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Manhattan distance on a 10x10 grid, from a corner to the opposite one
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .into_iter()
+                .map(move |(dx, dy)| ((x + dx, y + dy), 1))
+                .filter(|((x, y), _)| (0..10).contains(x) && (0..10).contains(y))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (9 - x) + (9 - y);
+        let search = astar([(0, 0)], neighbours, heuristic, |&p| p == (9, 9));
+        assert_eq!(search.cost(), Some(18));
+        assert_eq!(search.path().unwrap().len(), 19);
+        assert!(search.visited().count() < 100);
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    any::Any,
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::ParseError;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// An answer with lines on how it was found, which the runner prints
+    /// before the answer itself.
+    Detailed {
+        answer: Box<Answer>,
+        details: Vec<String>,
+    },
+}
+
+impl Answer {
+    pub fn with_details(self, details: Vec<String>) -> Self {
+        Answer::Detailed {
+            answer: Box::new(self),
+            details,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Detailed { answer, .. } => write!(f, "{answer}"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u16, u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// The answer to one part, or why the input has none.
+pub type PartResult = Result<Answer, Box<dyn Error>>;
+
+/// A puzzle solution: parse the input once, then solve both parts from it.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input) -> PartResult;
+    fn part2(&self, input: &Self::Input) -> PartResult;
+}
+
+/// Object-safe version of [`Solution`], so that days with different input
+/// types can be stored side by side.
+pub trait DynSolution {
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1_dyn(&self, input: &dyn Any) -> PartResult;
+    fn part2_dyn(&self, input: &dyn Any) -> PartResult;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn part1_dyn(&self, input: &dyn Any) -> PartResult {
+        self.part1(input.downcast_ref().expect("Input of another solution"))
+    }
+
+    fn part2_dyn(&self, input: &dyn Any) -> PartResult {
+        self.part2(input.downcast_ref().expect("Input of another solution"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            crate::parse_lines(input, |line| {
+                line.parse()
+                    .map_err(|_| ParseError::at(line, line, "Invalid number"))
+            })
+        }
+
+        fn part1(&self, input: &Self::Input) -> PartResult {
+            Ok(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(&self, input: &Self::Input) -> PartResult {
+            if input.len() < 2 {
+                return Err("Too few numbers".into());
+            }
+            Ok(format!("{} numbers", input.len()).into())
+        }
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        let detailed = Answer::from(7u16).with_details(vec!["3 + 4".to_string()]);
+        assert_eq!(detailed.to_string(), "7");
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solution: &dyn DynSolution = &Sum;
+        let input = solution.parse_dyn("1\n2\n3").unwrap();
+        assert_eq!(
+            solution.part1_dyn(input.as_ref()).unwrap(),
+            Answer::Number(6)
+        );
+        assert_eq!(
+            solution.part2_dyn(input.as_ref()).unwrap(),
+            "3 numbers".into()
+        );
+    }
+
+    #[test]
+    fn test_dyn_solution_part_error() {
+        let solution: &dyn DynSolution = &Sum;
+        let input = solution.parse_dyn("1").unwrap();
+        let err = solution.part2_dyn(input.as_ref()).unwrap_err();
+        assert_eq!(err.to_string(), "Too few numbers");
+    }
+
+    #[test]
+    fn test_dyn_solution_parse_error() {
+        let solution: &dyn DynSolution = &Sum;
+        let err = solution.parse_dyn("1\n2x\n3").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "2x"));
+    }
+}
@@ -0,0 +1,51 @@
+use common::{ParseError, PartResult, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, calories_per_elve: &Self::Input) -> PartResult {
+        Ok(run_part1(calories_per_elve).into())
+    }
+
+    fn part2(&self, calories_per_elve: &Self::Input) -> PartResult {
+        Ok(run_part2(calories_per_elve).into())
+    }
+}
+
+pub fn run_part1(calories_per_elve: &[u32]) -> u32 {
+    *calories_per_elve.iter().max().unwrap()
+}
+
+pub fn run_part2(calories_per_elve: &[u32]) -> u32 {
+    let mut calories_per_elve = calories_per_elve.to_vec();
+    calories_per_elve.sort();
+    let top3: Vec<&u32> = calories_per_elve.iter().rev().take(3).collect();
+    top3.into_iter().sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut calories_per_elve: Vec<u32> = Vec::new();
+    let mut acc = 0;
+
+    for line in input.lines() {
+        if line.is_empty() {
+            calories_per_elve.push(acc);
+            acc = 0;
+        } else {
+            let calories: u32 = line
+                .parse()
+                .map_err(|_| ParseError::at(input, line, "Invalid calories"))?;
+            acc += calories;
+        }
+    }
+
+    calories_per_elve.push(acc);
+
+    Ok(calories_per_elve)
+}
@@ -1,7 +1,7 @@
-const INPUT: &str = include_str!("../input.txt");
+use common::{parse_lines, ParseError, PartResult, Solution};
 
 #[derive(Clone, Debug)]
-enum Instruction {
+pub enum Instruction {
     Noop,
     AddX(i32),
 }
@@ -28,15 +28,25 @@ impl State {
     }
 }
 
-fn main() {
-    let instructions = parse_instructions(INPUT);
-    let result_part1 = sum_signal_strengths(&instructions);
-    println!("Part 1: {result_part1}");
-    let result_part2 = draw_display(&instructions);
-    println!("Part 2: {result_part2}")
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> PartResult {
+        Ok(sum_signal_strengths(instructions).into())
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> PartResult {
+        Ok(draw_display(instructions).into())
+    }
 }
 
-fn sum_signal_strengths(instructions: &[Instruction]) -> i32 {
+pub fn sum_signal_strengths(instructions: &[Instruction]) -> i32 {
     let states = run_instructions(instructions);
 
     states
@@ -50,9 +60,8 @@ fn sum_signal_strengths(instructions: &[Instruction]) -> i32 {
         .sum()
 }
 
-fn draw_display(instructions: &[Instruction]) -> i32 {
+pub fn draw_display(instructions: &[Instruction]) -> String {
     let states = run_instructions(instructions);
-    println!("{}", states.len());
 
     const DISPLAY_COLUMNS: usize = 40;
 
@@ -72,9 +81,9 @@ fn draw_display(instructions: &[Instruction]) -> i32 {
     display
         .as_bytes()
         .chunks(DISPLAY_COLUMNS)
-        .for_each(|row| println!("{}", String::from_utf8(row.to_vec()).unwrap()));
-
-    0
+        .map(|row| String::from_utf8(row.to_vec()).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 fn run_instructions(instructions: &[Instruction]) -> Vec<State> {
@@ -87,24 +96,26 @@ fn run_instructions(instructions: &[Instruction]) -> Vec<State> {
         .collect()
 }
 
-fn parse_instructions(input: &str) -> Vec<Instruction> {
-    input.lines().map(parse_moves_line).collect()
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, parse_moves_line)
 }
 
-fn parse_moves_line(line: &str) -> Instruction {
+fn parse_moves_line(line: &str) -> Result<Instruction, ParseError> {
     let mut iter = line.split(' ');
     let opcode = iter.next();
     let operand = iter.next();
     match opcode {
-        Some("noop") => Instruction::Noop,
+        Some("noop") => Ok(Instruction::Noop),
         Some("addx") => {
             if let Some(v) = operand {
-                Instruction::AddX(v.parse().expect("AddX: Invalid number"))
+                v.parse()
+                    .map(Instruction::AddX)
+                    .map_err(|_| ParseError::at(line, v, "AddX: Invalid number"))
             } else {
-                panic!("AddX: Missing operand")
+                Err(ParseError::at(line, line, "AddX: Missing operand"))
             }
         }
-        _ => panic!("Invalid instruction"),
+        _ => Err(ParseError::at(line, line, "Invalid instruction")),
     }
 }
 
@@ -116,7 +127,13 @@ mod tests {
 
     #[test]
     fn test_input_part1() {
-        let instructions = parse_instructions(INPUT_TEST);
+        let instructions = parse_instructions(INPUT_TEST).unwrap();
         assert_eq!(sum_signal_strengths(&instructions), 13140);
     }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_instructions("noop\naddx three").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, "three"));
+    }
 }
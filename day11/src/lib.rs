@@ -0,0 +1,230 @@
+use std::collections::VecDeque;
+
+use common::{ParseError, PartResult, Solution};
+use itertools::Itertools;
+
+fn monkey_modulus(monkeys: &[Monkey]) -> Item {
+    monkeys.iter().map(|m| m.test_divisible_by).product()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> PartResult {
+        Ok(run_part1(monkeys).into())
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> PartResult {
+        Ok(run_part2(monkeys).into())
+    }
+}
+
+pub fn run_part1(monkeys: &[Monkey]) -> u64 {
+    run(monkeys, 20, true)
+}
+
+pub fn run_part2(monkeys: &[Monkey]) -> u64 {
+    run(monkeys, 10000, false)
+}
+
+fn run(monkeys: &[Monkey], rounds: usize, divide_by_three: bool) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let modulus = monkey_modulus(&monkeys);
+    for _round in 0..rounds {
+        for i in 0..monkeys.len() {
+            // iterate using index to avoid mutable borrowing
+            let throws = monkeys[i].inspect_and_throw_items(divide_by_three, modulus);
+            for (monkey_id, item) in throws {
+                monkeys[monkey_id].catch_item(item);
+            }
+        }
+
+        // if (_round + 1) % 1000 == 0 {
+        //     eprintln!(
+        //         "After round {}\n{}\n",
+        //         _round + 1,
+        //         monkeys
+        //             .iter()
+        //             .enumerate()
+        //             .map(|(i, m)| format!(
+        //                 "Monkey {} inspected {} items and holds {:?}",
+        //                 i, m.inspection_count, m.queue
+        //             ))
+        //             .join("\n")
+        //     )
+        // }
+    }
+
+    // println!("{:#?}", monkeys);
+    calculate_monkey_business_level(&monkeys)
+}
+
+fn calculate_monkey_business_level(monkeys: &[Monkey]) -> u64 {
+    let mut inspection_counts: Vec<_> = monkeys
+        .iter()
+        .map(|monkey| monkey.inspection_count)
+        .collect();
+    inspection_counts.sort_by(|a, b| b.cmp(a));
+    inspection_counts.into_iter().take(2).product()
+}
+
+type Item = u64;
+type MonkeyId = usize;
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Add(Item),
+    Multiply(Item),
+    Square,
+}
+
+impl Operation {
+    fn execute(&self, x: Item) -> Item {
+        match self {
+            Self::Add(y) => x + y,
+            Self::Multiply(y) => x * y,
+            Self::Square => x * x,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    queue: VecDeque<Item>,
+    inspection_count: u64,
+    operation: Operation,
+    test_divisible_by: Item,
+    if_true_throw_to: MonkeyId,
+    if_false_throw_to: MonkeyId,
+}
+
+impl Monkey {
+    fn inspect_and_throw_items(
+        &mut self,
+        divide_by_three: bool,
+        modulus: Item,
+    ) -> Vec<(MonkeyId, Item)> {
+        let mut throws = vec![];
+        while let Some(item) = self.queue.pop_front() {
+            self.inspection_count += 1;
+            let item_after_inspection = self.operation.execute(item);
+            let item_after_adjustment = if divide_by_three {
+                item_after_inspection / 3
+            } else {
+                item_after_inspection % modulus
+            };
+            if item_after_adjustment % self.test_divisible_by == 0 {
+                throws.push((self.if_true_throw_to, item_after_adjustment));
+            } else {
+                throws.push((self.if_false_throw_to, item_after_adjustment));
+            }
+        }
+        throws
+    }
+
+    fn catch_item(&mut self, item: Item) {
+        self.queue.push_back(item);
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = input
+        .lines()
+        .chunks(7)
+        .into_iter()
+        .map(|monkey_lines| parse_monkey(input, &monkey_lines.collect_vec()))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (line, monkey) in input.lines().skip(4).step_by(7).zip(&monkeys) {
+        if monkey.if_true_throw_to >= monkeys.len() {
+            return Err(ParseError::at(input, line, "No such monkey"));
+        }
+    }
+    for (line, monkey) in input.lines().skip(5).step_by(7).zip(&monkeys) {
+        if monkey.if_false_throw_to >= monkeys.len() {
+            return Err(ParseError::at(input, line, "No such monkey"));
+        }
+    }
+    Ok(monkeys)
+}
+
+// ugly monkey parser
+fn parse_monkey(input: &str, lines: &[&str]) -> Result<Monkey, ParseError> {
+    let field = |ix: usize, prefix: &str| {
+        let line = *lines.get(ix).ok_or_else(|| {
+            let last = lines.last().copied().unwrap_or(input);
+            ParseError::at(input, last, "Incomplete monkey")
+        })?;
+        line.strip_prefix(prefix)
+            .ok_or_else(|| ParseError::at(input, line, format!("Expected '{}'", prefix.trim())))
+    };
+    let number = |s: &str| {
+        s.parse::<Item>()
+            .map_err(|_| ParseError::at(input, s, "Invalid number"))
+    };
+
+    field(0, "Monkey ")?;
+    let initial_items = field(1, "  Starting items: ")?
+        .split(", ")
+        .filter(|item| !item.is_empty())
+        .map(number)
+        .collect::<Result<Vec<_>, _>>()?;
+    let operation = match field(2, "  Operation: new = old ")?.split_once(' ') {
+        Some(("*", "old")) => Operation::Square,
+        Some(("+", operand)) => Operation::Add(number(operand)?),
+        Some(("*", operand)) => Operation::Multiply(number(operand)?),
+        _ => return Err(ParseError::at(input, lines[2], "Invalid operation")),
+    };
+    let test_divisible_by = number(field(3, "  Test: divisible by ")?)?;
+    if test_divisible_by == 0 {
+        return Err(ParseError::at(input, lines[3], "Division by zero"));
+    }
+    let if_true_throw_to = number(field(4, "    If true: throw to monkey ")?)? as MonkeyId;
+    let if_false_throw_to = number(field(5, "    If false: throw to monkey ")?)? as MonkeyId;
+
+    Ok(Monkey {
+        queue: VecDeque::from(initial_items),
+        inspection_count: 0,
+        operation,
+        test_divisible_by,
+        if_true_throw_to,
+        if_false_throw_to,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input.txt");
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&parse_input(INPUT_TEST).unwrap()), 10605);
+    }
+
+    #[test]
+    fn test_real_input_part1() {
+        assert_eq!(run_part1(&parse_input(INPUT).unwrap()), 98280);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&parse_input(INPUT_TEST).unwrap()), 2713310158);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT_TEST.replace("new = old + 6", "new = old - 6");
+        let err = parse_input(&input).err().unwrap();
+        assert_eq!((err.line, err.column), (10, 1));
+        let input = INPUT_TEST.replace("throw to monkey 3", "throw to monkey 7");
+        assert_eq!(parse_input(&input).err().unwrap().line, 6);
+    }
+}
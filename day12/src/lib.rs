@@ -0,0 +1,262 @@
+use common::{search, ParseError, PartResult, Solution};
+use grid::{Grid, Pos};
+
+#[derive(Clone, Debug)]
+pub struct HeightMap {
+    elevations: Grid<u8>,
+    start: Pos,
+    end: Pos,
+}
+
+/// The number of steps from every square to E, from a single search
+/// backwards from E. Squares that cannot reach E have no distance.
+#[derive(Clone, Debug)]
+pub struct DistanceField {
+    distances: Grid<Option<usize>>,
+    elevations: Grid<u8>,
+    start: Pos,
+    /// Squares at elevation `a` that can reach E, closest first.
+    lowest: Vec<(usize, Pos)>,
+}
+
+impl DistanceField {
+    pub fn new(map: &HeightMap) -> Self {
+        let elevations = &map.elevations;
+        let search = search::bfs(
+            [map.end],
+            |&pos| {
+                elevations
+                    .neighbours4(pos)
+                    .filter(move |&prev| elevations[pos] <= elevations[prev] + 1)
+            },
+            |_| false,
+        );
+        let distances = Grid::from_fn(elevations.width(), elevations.height(), |pos| {
+            search.cost_to(&pos)
+        });
+        let mut lowest: Vec<_> = distances
+            .iter()
+            .filter(|&(pos, _)| elevations[pos] == b'a')
+            .filter_map(|(pos, distance)| Some(((*distance)?, pos)))
+            .collect();
+        lowest.sort();
+        Self {
+            distances,
+            elevations: elevations.clone(),
+            start: map.start,
+            lowest,
+        }
+    }
+
+    pub fn distance(&self, pos: Pos) -> Option<usize> {
+        self.distances.get(pos).copied().flatten()
+    }
+
+    pub fn from_start(&self) -> Option<usize> {
+        self.distance(self.start)
+    }
+
+    /// The distance from the closest square at elevation `a`.
+    pub fn from_lowest(&self) -> Option<usize> {
+        self.lowest.first().map(|&(distance, _)| distance)
+    }
+
+    /// Up to `k` squares at elevation `a` with their distances, closest first.
+    pub fn closest_lowest(&self, k: usize) -> &[(usize, Pos)] {
+        &self.lowest[..k.min(self.lowest.len())]
+    }
+
+    /// The shortest route from `pos` to E, both included, found by stepping
+    /// to a neighbour one step closer to E every time.
+    pub fn path_from(&self, mut pos: Pos) -> Option<Vec<Pos>> {
+        let mut distance = self.distance(pos)?;
+        let mut path = vec![pos];
+        while distance > 0 {
+            pos = self
+                .elevations
+                .neighbours4(pos)
+                .find(|&next| {
+                    self.distance(next) == Some(distance - 1)
+                        && self.elevations[next] <= self.elevations[pos] + 1
+                })
+                .expect("A square with a distance has a neighbour one step closer");
+            distance -= 1;
+            path.push(pos);
+        }
+        Some(path)
+    }
+}
+
+/// Solution for day 12; the distance field is built once while parsing and
+/// answers both parts.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = DistanceField;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(DistanceField::new(&parse(input)?))
+    }
+
+    fn part1(&self, field: &Self::Input) -> PartResult {
+        let steps = run_part1(field).ok_or("E cannot be reached from S")?;
+        Ok(steps.into())
+    }
+
+    fn part2(&self, field: &Self::Input) -> PartResult {
+        let steps = run_part2(field).ok_or("E cannot be reached from any square at elevation a")?;
+        Ok(steps.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<HeightMap, ParseError> {
+    let mut elevations = Grid::parse(input, |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c as u8)
+    })?;
+    for marker in ["S", "E"] {
+        let mut found = input
+            .match_indices(marker)
+            .map(|(ix, _)| &input[ix..ix + 1]);
+        match (found.next(), found.next()) {
+            (Some(_), None) => (),
+            (None, _) => return Err(ParseError::at(input, input, format!("Missing '{marker}'"))),
+            (Some(_), Some(second)) => {
+                return Err(ParseError::at(
+                    input,
+                    second,
+                    format!("Duplicate '{marker}'"),
+                ))
+            }
+        }
+    }
+
+    let start = elevations.position(|&b| b == b'S').unwrap();
+    let end = elevations.position(|&b| b == b'E').unwrap();
+    elevations[start] = b'a';
+    elevations[end] = b'z';
+    Ok(HeightMap {
+        elevations,
+        start,
+        end,
+    })
+}
+
+pub fn run_part1(field: &DistanceField) -> Option<usize> {
+    field.from_start()
+}
+
+pub fn run_part2(field: &DistanceField) -> Option<usize> {
+    field.from_lowest()
+}
+
+/// The shortest route from S to E, both included.
+pub fn shortest_path(field: &DistanceField) -> Option<Vec<Pos>> {
+    field.path_from(field.start)
+}
+
+/// The shortest route from any square at elevation `a` to E, both included.
+pub fn shortest_path_from_lowest(field: &DistanceField) -> Option<Vec<Pos>> {
+    let &(_, lowest) = field.lowest.first()?;
+    field.path_from(lowest)
+}
+
+/// Draws `path` over the elevation map, with each square on it showing the
+/// direction of the next step as in the puzzle description.
+pub fn render_path(map: &HeightMap, path: &[Pos]) -> String {
+    let mut overlay = map.elevations.map(|&elevation| elevation as char);
+    overlay[map.start] = 'S';
+    overlay[map.end] = 'E';
+    for step in path.windows(2) {
+        overlay[step[0]] = match (step[1].0 - step[0].0, step[1].1 - step[0].1) {
+            (1, 0) => '>',
+            (-1, 0) => '<',
+            (0, 1) => 'v',
+            (0, -1) => '^',
+            _ => panic!("Steps on a route are between neighbours"),
+        };
+    }
+    overlay.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        let field = DistanceField::new(&parse(INPUT_TEST).unwrap());
+        assert_eq!(run_part1(&field), Some(31));
+    }
+
+    #[test]
+    fn test_input_part2() {
+        let field = DistanceField::new(&parse(INPUT_TEST).unwrap());
+        assert_eq!(run_part2(&field), Some(29));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // the cliff up to E cannot be climbed
+        let input = "Sac\nabE";
+        let field = DistanceField::new(&parse(input).unwrap());
+        assert_eq!(run_part1(&field), None);
+        assert_eq!(run_part2(&field), None);
+
+        let field = Day12.parse(input).unwrap();
+        let err = Day12.part1(&field).unwrap_err();
+        assert_eq!(err.to_string(), "E cannot be reached from S");
+    }
+
+    #[test]
+    fn test_distance_field() {
+        let map = parse(INPUT_TEST).unwrap();
+        let field = DistanceField::new(&map);
+        assert_eq!(field.distance(map.end), Some(0));
+        assert_eq!(field.distance((0, 4)), Some(29));
+        assert_eq!(field.distance((-1, 0)), None);
+        assert_eq!(
+            field.closest_lowest(3),
+            &[(29, (0, 4)), (30, (0, 1)), (30, (0, 3))]
+        );
+        assert_eq!(field.closest_lowest(100).len(), 6);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let map = parse(INPUT_TEST).unwrap();
+        let field = DistanceField::new(&map);
+        let path = shortest_path(&field).unwrap();
+        assert_eq!(path.first(), Some(&map.start));
+        assert_eq!(path.last(), Some(&map.end));
+        assert_eq!(path.len(), 32);
+
+        let path = shortest_path_from_lowest(&field).unwrap();
+        assert_eq!(map.elevations[path[0]], b'a');
+        assert_eq!(path.last(), Some(&map.end));
+        assert_eq!(path.len(), 30);
+    }
+
+    #[test]
+    fn test_render_path() {
+        let map = parse(INPUT_TEST).unwrap();
+        let field = DistanceField::new(&map);
+        let rendered = render_path(&map, &shortest_path(&field).unwrap());
+        assert_eq!(
+            rendered,
+            ">>vv<<<<\n\
+             abvvv<<^\n\
+             acvv>E^^\n\
+             acv>>>^^\n\
+             ab>>>>>^"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Sab\nacE\naSa").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 2, "S"));
+        assert_eq!(parse("Sab\nac\nabE").err().unwrap().line, 2);
+    }
+}
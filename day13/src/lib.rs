@@ -1,17 +1,16 @@
 use std::cmp::Ordering;
-use std::time::Instant;
 
 use nom::branch::alt;
 use nom::character::complete::char;
-use nom::combinator::{map, opt};
+use nom::combinator::map;
 use nom::multi::separated_list0;
-use nom::sequence::{delimited, pair, terminated};
+use nom::sequence::delimited;
 use nom::IResult;
 
-const INPUT: &str = include_str!("../input.txt");
+use common::{ParseError, PartResult, Solution};
 
 #[derive(Clone, Debug, Eq)]
-enum Elem {
+pub enum Elem {
     Int(i32),
     List(Vec<Elem>),
 }
@@ -50,20 +49,27 @@ impl Ord for Elem {
     }
 }
 
-fn main() {
-    let start_part1 = Instant::now();
-    let result_part1 = run_part1(INPUT);
-    let elapsed_time_part1 = start_part1.elapsed().as_millis();
-    println!("Part 1: {result_part1} in {elapsed_time_part1} ms");
+pub type PacketPair = (Elem, Elem);
 
-    let start_part2 = Instant::now();
-    let result_part2 = run_part2(INPUT);
-    let elapsed_time_part2 = start_part2.elapsed().as_millis();
-    println!("Part 2: {result_part2} in {elapsed_time_part2} ms")
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<PacketPair>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, packet_pairs: &Self::Input) -> PartResult {
+        Ok(run_part1(packet_pairs).into())
+    }
+
+    fn part2(&self, packet_pairs: &Self::Input) -> PartResult {
+        Ok(run_part2(packet_pairs).into())
+    }
 }
 
-fn run_part1(input: &str) -> usize {
-    let packet_pairs = parse(input).unwrap().1;
+pub fn run_part1(packet_pairs: &[PacketPair]) -> usize {
     packet_pairs
         .iter()
         .enumerate()
@@ -71,15 +77,14 @@ fn run_part1(input: &str) -> usize {
         .sum()
 }
 
-fn run_part2(input: &str) -> usize {
-    let packet_pairs = parse(input).unwrap().1;
-    let divider_packets = vec![
+pub fn run_part2(packet_pairs: &[PacketPair]) -> usize {
+    let divider_packets = [
         Elem::List(vec![Elem::List(vec![Elem::Int(2)])]),
         Elem::List(vec![Elem::List(vec![Elem::Int(6)])]),
     ];
     let mut all_packets: Vec<_> = packet_pairs
-        .into_iter()
-        .flat_map(|(a, b)| vec![a, b])
+        .iter()
+        .flat_map(|(a, b)| vec![a.clone(), b.clone()])
         .chain(divider_packets.iter().cloned())
         .collect();
     all_packets.sort();
@@ -90,16 +95,24 @@ fn run_part2(input: &str) -> usize {
         .product()
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<(Elem, Elem)>> {
-    separated_list0(char('\n'), packet_pair)(input)
-}
-
-fn packet_pair(input: &str) -> IResult<&str, (Elem, Elem)> {
-    pair(packet, packet)(input)
+pub fn parse(input: &str) -> Result<Vec<PacketPair>, ParseError> {
+    let lines: Vec<_> = input.lines().filter(|line| !line.is_empty()).collect();
+    if lines.len() % 2 != 0 {
+        let last = lines.last().unwrap();
+        return Err(ParseError::at(input, last, "Packet without a pair"));
+    }
+    lines
+        .chunks(2)
+        .map(|pair| Ok((packet(input, pair[0])?, packet(input, pair[1])?)))
+        .collect()
 }
 
-fn packet(input: &str) -> IResult<&str, Elem> {
-    terminated(elem, opt(char('\n')))(input)
+fn packet(input: &str, line: &str) -> Result<Elem, ParseError> {
+    match elem(line) {
+        Ok(("", packet)) => Ok(packet),
+        Ok((rest, _)) => Err(ParseError::at(input, rest, "Unexpected text after packet")),
+        Err(_) => Err(ParseError::at(input, line, "Invalid packet")),
+    }
 }
 
 fn elem(input: &str) -> IResult<&str, Elem> {
@@ -132,16 +145,23 @@ mod tests {
     #[test]
     fn test_parse() {
         let result = parse(INPUT_TEST).unwrap();
-        assert_eq!(result.1.len(), 8)
+        assert_eq!(result.len(), 8)
     }
 
     #[test]
     fn test_input_part1() {
-        assert_eq!(run_part1(INPUT_TEST), 13);
+        assert_eq!(run_part1(&parse(INPUT_TEST).unwrap()), 13);
     }
 
     #[test]
     fn test_input_part2() {
-        assert_eq!(run_part2(INPUT_TEST), 140);
+        assert_eq!(run_part2(&parse(INPUT_TEST).unwrap()), 140);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("[1,1]\n[1,2]]\n\n[3]\n[4]").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, "]"));
+        assert_eq!(parse("[1]\n[2]\n\n[3]").err().unwrap().line, 4);
     }
 }
@@ -0,0 +1,241 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::bytes::complete::tag;
+use nom::character::complete::char;
+use nom::combinator::map;
+use nom::multi::separated_list0;
+use nom::sequence::tuple;
+use nom::IResult;
+
+use common::{parse_lines, ParseError, PartResult, Solution};
+use grid::{Grid, Pos};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Point(usize, usize);
+
+impl Point {
+    fn pos(&self) -> Pos {
+        (self.0 as i64, self.1 as i64)
+    }
+}
+
+#[derive(Debug)]
+pub struct Path {
+    points: Vec<Point>,
+}
+
+impl Path {
+    fn x_min(&self) -> usize {
+        self.points.iter().map(|p| p.0).min().unwrap()
+    }
+
+    fn x_max(&self) -> usize {
+        self.points.iter().map(|p| p.0).max().unwrap()
+    }
+
+    fn y_max(&self) -> usize {
+        self.points.iter().map(|p| p.1).max().unwrap()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Elem {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Display for Elem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Elem::Air => write!(f, "."),
+            Elem::Rock => write!(f, "#"),
+            Elem::Sand => write!(f, "o"),
+        }
+    }
+}
+
+const SAND_ORIGIN: Pos = (500, 0);
+
+/// A cave just large enough for the paths and the sand origin.
+fn fit_paths(paths: &[Path]) -> Grid<Elem> {
+    let x_min = paths.iter().map(Path::x_min).min().unwrap() as i64;
+    let x_max = paths.iter().map(Path::x_max).max().unwrap() as i64;
+    let y_max = paths.iter().map(Path::y_max).max().unwrap();
+    let mut cave = Grid::with_origin(
+        (x_min, 0),
+        (x_max - x_min) as usize + 1,
+        y_max + 1,
+        Elem::Air,
+    );
+    cave.grow_to_include(SAND_ORIGIN, Elem::Air);
+    for path in paths {
+        draw_path(&mut cave, path, Elem::Rock);
+    }
+    cave
+}
+
+fn draw_path(cave: &mut Grid<Elem>, path: &Path, elem: Elem) {
+    path.points
+        .windows(2)
+        .for_each(|line| draw_line(cave, line[0].pos(), line[1].pos(), elem));
+}
+
+fn draw_line(cave: &mut Grid<Elem>, from: Pos, to: Pos, elem: Elem) {
+    if from.0 == to.0 {
+        for y in from.1.min(to.1)..=from.1.max(to.1) {
+            cave[(from.0, y)] = elem;
+        }
+    } else {
+        for x in from.0.min(to.0)..=from.0.max(to.0) {
+            cave[(x, from.1)] = elem;
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Path>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_paths(input)
+    }
+
+    fn part1(&self, paths: &Self::Input) -> PartResult {
+        Ok(run_part1(paths).into())
+    }
+
+    fn part2(&self, paths: &Self::Input) -> PartResult {
+        Ok(run_part2(paths).into())
+    }
+}
+
+pub fn run_part1(paths: &[Path]) -> usize {
+    let mut cave = fit_paths(paths);
+
+    let mut sand_counter = 0;
+    while let Some(_final_pos) = drop_sand(&mut cave, None, SAND_ORIGIN) {
+        sand_counter += 1;
+    }
+    // println!("{}", cave);
+
+    sand_counter
+}
+
+pub fn run_part2(paths: &[Path]) -> usize {
+    let mut cave = fit_paths(paths);
+    // the floor is infinite, so the cave grows sideways as the sand spreads
+    let floor = cave.y_range().end + 1;
+
+    let mut sand_counter = 0;
+    while let Some(final_pos) = drop_sand(&mut cave, Some(floor), SAND_ORIGIN) {
+        sand_counter += 1;
+        if final_pos == SAND_ORIGIN {
+            break;
+        }
+    }
+    // println!("{}", cave);
+
+    sand_counter
+}
+
+/// Lets one unit of sand fall from `pos` and returns where it comes to rest,
+/// or `None` if it falls out of a cave without `floor`.
+fn drop_sand(cave: &mut Grid<Elem>, floor: Option<i64>, mut pos: Pos) -> Option<Pos> {
+    'falling: loop {
+        for dx in [0, -1, 1] {
+            let next = (pos.0 + dx, pos.1 + 1);
+            let elem = match floor {
+                Some(floor) if next.1 == floor => Elem::Rock,
+                Some(floor) => {
+                    if !cave.contains(next) {
+                        // sand spreads at most one column per row, so grow as far as it can reach
+                        let reach = (SAND_ORIGIN.0 + dx * floor, next.1);
+                        cave.grow_to_include(reach, Elem::Air);
+                    }
+                    cave[next]
+                }
+                None => *cave.get(next)?,
+            };
+            if elem == Elem::Air {
+                pos = next;
+                continue 'falling;
+            }
+        }
+        cave[pos] = Elem::Sand;
+        return Some(pos);
+    }
+}
+
+pub fn parse_paths(input: &str) -> Result<Vec<Path>, ParseError> {
+    let paths = parse_lines(input, |line| match path(line) {
+        Ok(("", path)) if !path.points.is_empty() => Ok(path),
+        Ok((rest, _)) if !rest.is_empty() => {
+            let point = rest.trim_start_matches(" -> ");
+            Err(ParseError::at(line, point, "Invalid point"))
+        }
+        _ => Err(ParseError::at(line, line, "Invalid path")),
+    })?;
+    if paths.is_empty() {
+        return Err(ParseError::at(input, input, "No rock paths"));
+    }
+    Ok(paths)
+}
+
+fn path(input: &str) -> IResult<&str, Path> {
+    map(separated_list0(tag(" -> "), point), |points| Path {
+        points,
+    })(input)
+}
+
+fn point(input: &str) -> IResult<&str, Point> {
+    map(
+        tuple((
+            nom::character::complete::u32,
+            char(','),
+            nom::character::complete::u32,
+        )),
+        |(x, _, y)| Point(x as usize, y as usize),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_parse_paths() {
+        let paths = parse_paths(INPUT_TEST).unwrap();
+        assert_eq!(paths.len(), 2);
+
+        let first_path = paths.first().unwrap();
+        assert_eq!(
+            first_path.points,
+            vec![Point(498, 4), Point(498, 6), Point(496, 6)]
+        );
+
+        assert_eq!(paths.get(1).unwrap().points.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_paths_error() {
+        let err = parse_paths("498,4 -> 498,6\n503,4 -> 502;4").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 10, "502;4"));
+
+        let err = parse_paths("").err().unwrap();
+        assert_eq!(err.message, "No rock paths");
+    }
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&parse_paths(INPUT_TEST).unwrap()), 24);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&parse_paths(INPUT_TEST).unwrap()), 93);
+    }
+}
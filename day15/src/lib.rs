@@ -0,0 +1,325 @@
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Formatter},
+    ops::RangeInclusive,
+};
+
+use common::{parse_lines, ParseError, PartResult, Solution};
+use grid::Grid;
+use parse_display::{Display, FromStr};
+
+#[derive(Display, Clone, Copy, Hash, FromStr, PartialEq, Eq, Debug)]
+#[display("x={x}, y={y}")]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    fn distance(&self, other: &Point) -> u32 {
+        (self.x - other.x).unsigned_abs() + (self.y - other.y).unsigned_abs()
+    }
+}
+
+#[derive(Display, Eq, Hash, FromStr, PartialEq, Debug)]
+#[display("Sensor at {location}: closest beacon is at {nearest_beacon}")]
+pub struct Sensor {
+    location: Point,
+    nearest_beacon: Point,
+}
+
+impl Sensor {
+    fn radius(&self) -> i32 {
+        self.location.distance(&self.nearest_beacon) as i32
+    }
+
+    fn covers(&self, point: &Point) -> bool {
+        self.location.distance(point) as i32 <= self.radius()
+    }
+
+    /// The x range that the sensor covers on row `y`, both ends included.
+    pub fn covered_interval(&self, y: i32) -> Option<(i32, i32)> {
+        let dy = (self.location.y - y).abs();
+        let dist = self.location.distance(&self.nearest_beacon) as i32;
+        if dy > dist {
+            return None;
+        }
+        let from = self.location.x - dist + dy;
+        let to = self.location.x + dist - dy;
+        Some((from, to))
+    }
+}
+
+/// Solution for day 15; the row to check in part 1 and the search area for
+/// part 2 differ between the example and the real input.
+pub struct Day15 {
+    pub row: i32,
+    pub max_loc: usize,
+}
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, sensors: &Self::Input) -> PartResult {
+        Ok(run_part1(sensors, self.row).into())
+    }
+
+    fn part2(&self, sensors: &Self::Input) -> PartResult {
+        Ok(run_part2(sensors, self.max_loc)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    parse_lines(input, |line| {
+        line.parse::<Sensor>()
+            .map_err(|_| ParseError::at(line, line, "Invalid sensor"))
+    })
+}
+
+/// The x ranges covered by any sensor on row `y`, both ends included, sorted
+/// and merged so that no two of them overlap or touch.
+pub fn coverage_on_row(sensors: &[Sensor], y: i32) -> Vec<(i32, i32)> {
+    let mut intervals: Vec<_> = sensors
+        .iter()
+        .filter_map(|sensor| sensor.covered_interval(y))
+        .collect();
+    intervals.sort_unstable();
+
+    let mut merged: Vec<(i32, i32)> = Vec::with_capacity(intervals.len());
+    for (from, to) in intervals {
+        match merged.last_mut() {
+            Some(last) if from <= last.1 + 1 => last.1 = last.1.max(to),
+            _ => merged.push((from, to)),
+        }
+    }
+    merged
+}
+
+/// The number of positions on row `y` where the distress beacon cannot be.
+pub fn run_part1(sensors: &[Sensor], y: i32) -> usize {
+    let coverage = coverage_on_row(sensors, y);
+    let covered: usize = coverage
+        .iter()
+        .map(|(from, to)| (to - from + 1) as usize)
+        .sum();
+    let beacons: HashSet<i32> = sensors
+        .iter()
+        .map(|sensor| sensor.nearest_beacon)
+        .filter(|beacon| beacon.y == y)
+        .map(|beacon| beacon.x)
+        .collect();
+    let covered_beacons = beacons
+        .iter()
+        .filter(|&&x| coverage.iter().any(|&(from, to)| (from..=to).contains(&x)))
+        .count();
+    covered - covered_beacons
+}
+
+/// Draws the part of the map in `xs` and `ys` as the puzzle does: `S` for
+/// sensors, `B` for beacons, `#` where no beacon can be and `D` for
+/// `distress_beacon`, if given.
+pub fn render(
+    sensors: &[Sensor],
+    xs: RangeInclusive<i32>,
+    ys: RangeInclusive<i32>,
+    distress_beacon: Option<Point>,
+) -> String {
+    let mut map = Grid::with_origin(
+        (*xs.start() as i64, *ys.start() as i64),
+        xs.clone().count(),
+        ys.clone().count(),
+        '.',
+    );
+    for y in ys {
+        for (from, to) in coverage_on_row(sensors, y) {
+            for x in from.max(*xs.start())..=to.min(*xs.end()) {
+                map[(x as i64, y as i64)] = '#';
+            }
+        }
+    }
+
+    let markers = sensors
+        .iter()
+        .flat_map(|sensor| [(sensor.nearest_beacon, 'B'), (sensor.location, 'S')])
+        .chain(distress_beacon.map(|beacon| (beacon, 'D')));
+    for (point, marker) in markers {
+        if let Some(cell) = map.get_mut((point.x as i64, point.y as i64)) {
+            *cell = marker;
+        }
+    }
+    map.to_string()
+}
+
+/// Why the distress beacon could not be located.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DistressBeaconError {
+    NoGap,
+    /// Positions out of reach of the sensors. Only the candidate positions
+    /// are checked, so the list is partial when a larger area is uncovered.
+    MultipleGaps(Vec<Point>),
+}
+
+impl fmt::Display for DistressBeaconError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DistressBeaconError::NoGap => write!(f, "No position is out of reach of the sensors"),
+            DistressBeaconError::MultipleGaps(gaps) => {
+                write!(
+                    f,
+                    "At least {} positions are out of reach of the sensors",
+                    gaps.len()
+                )
+            }
+        }
+    }
+}
+
+impl Error for DistressBeaconError {}
+
+/// The only position with both coordinates in `0..=max_loc` that no sensor
+/// covers.
+///
+/// A lone gap is surrounded by covered positions, so it lies just outside the
+/// range of the sensors around it: on one of their `x + y` and one of their
+/// `x - y` boundary lines, or on the edge of the search area. Only the
+/// intersections of those lines need checking.
+pub fn distress_beacon(sensors: &[Sensor], max_loc: i32) -> Result<Point, DistressBeaconError> {
+    let mut sums = vec![0, 2 * max_loc];
+    let mut diffs = vec![-max_loc, max_loc];
+    for sensor in sensors {
+        let Point { x, y } = sensor.location;
+        let reach = sensor.radius() + 1;
+        sums.extend([x + y - reach, x + y + reach]);
+        diffs.extend([x - y - reach, x - y + reach]);
+    }
+
+    let area = 0..=max_loc;
+    let on_lines = sums.iter().flat_map(|&sum| {
+        diffs
+            .iter()
+            .filter(move |&&diff| (sum + diff) % 2 == 0)
+            .map(move |&diff| Point {
+                x: (sum + diff) / 2,
+                y: (sum - diff) / 2,
+            })
+    });
+    // where the boundary lines meet the edges of the search area
+    let on_edges = sums.iter().chain(&diffs).flat_map(|&c| {
+        [0, max_loc].into_iter().flat_map(move |edge| {
+            [
+                Point {
+                    x: edge,
+                    y: c - edge,
+                },
+                Point {
+                    x: c - edge,
+                    y: edge,
+                },
+                Point {
+                    x: edge,
+                    y: edge - c,
+                },
+                Point {
+                    x: edge + c,
+                    y: edge,
+                },
+            ]
+        })
+    });
+
+    let mut gaps: Vec<_> = on_lines
+        .chain(on_edges)
+        .filter(|p| area.contains(&p.x) && area.contains(&p.y))
+        .filter(|p| !sensors.iter().any(|sensor| sensor.covers(p)))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    match gaps.len() {
+        0 => Err(DistressBeaconError::NoGap),
+        1 => Ok(gaps[0]),
+        _ => {
+            gaps.sort_by_key(|p| (p.y, p.x));
+            Err(DistressBeaconError::MultipleGaps(gaps))
+        }
+    }
+}
+
+/// The tuning frequency of the distress beacon.
+pub fn run_part2(sensors: &[Sensor], max_loc: usize) -> Result<i64, DistressBeaconError> {
+    let beacon = distress_beacon(sensors, max_loc as i32)?;
+    Ok(beacon.x as i64 * 4000000 + beacon.y as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn test_coverage_on_row() {
+        let sensors = parse(INPUT_TEST).unwrap();
+        assert_eq!(coverage_on_row(&sensors, 10), vec![(-2, 24)]);
+        assert_eq!(coverage_on_row(&sensors, 11), vec![(-3, 13), (15, 25)]);
+        assert_eq!(coverage_on_row(&sensors, -10), vec![(2, 2)]);
+        assert_eq!(coverage_on_row(&sensors, 100), vec![]);
+    }
+
+    #[test]
+    fn test_render() {
+        let sensors = parse(INPUT_TEST).unwrap();
+        assert_eq!(
+            render(&sensors, -4..=26, 9..=11, None),
+            "...#########################...\n\
+             ..####B######################..\n\
+             .###S#############.###########."
+        );
+        let beacon = distress_beacon(&sensors, 20).ok();
+        assert_eq!(
+            render(&sensors, 12..=16, 10..=12, beacon),
+            "#####\n\
+             ##D##\n\
+             #####"
+        );
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST).unwrap(), 20), Ok(56000011));
+    }
+
+    #[test]
+    fn test_distress_beacon_gaps() {
+        let sensors = parse(INPUT_TEST).unwrap();
+        assert_eq!(distress_beacon(&sensors, 20), Ok(Point { x: 14, y: 11 }));
+        // a smaller area is fully covered
+        assert_eq!(
+            distress_beacon(&sensors, 10),
+            Err(DistressBeaconError::NoGap)
+        );
+
+        // a sensor that only covers its own position leaves the corners open
+        let sensors = parse("Sensor at x=1, y=1: closest beacon is at x=1, y=1").unwrap();
+        let Err(DistressBeaconError::MultipleGaps(gaps)) = distress_beacon(&sensors, 2) else {
+            panic!("Expected several gaps");
+        };
+        assert_eq!(gaps.len(), 8);
+    }
+
+    #[test]
+    fn test_distress_beacon_on_edge() {
+        // everything but (0, 0) is within reach of the sensor
+        let sensors = parse("Sensor at x=3, y=3: closest beacon is at x=3, y=8").unwrap();
+        assert_eq!(distress_beacon(&sensors, 3), Ok(Point { x: 0, y: 0 }));
+    }
+}
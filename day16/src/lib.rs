@@ -0,0 +1,240 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use common::{search, ParseError, PartResult, Solution};
+use regex::Regex;
+
+const START: &str = "AA";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Valve {
+    pub name: String,
+    pub flow: u32,
+    pub tunnels: Vec<String>,
+}
+
+/// The valves worth opening, with the travel time between every pair of them.
+/// The start valve is the last row of `distances` whatever its flow rate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Network {
+    flows: Vec<u32>,
+    distances: Vec<Vec<u32>>,
+}
+
+impl Network {
+    pub fn new(valves: &[Valve]) -> Self {
+        let index: HashMap<&str, usize> = valves
+            .iter()
+            .enumerate()
+            .map(|(ix, valve)| (valve.name.as_str(), ix))
+            .collect();
+        let tunnels: Vec<Vec<usize>> = valves
+            .iter()
+            .map(|valve| valve.tunnels.iter().map(|t| index[t.as_str()]).collect())
+            .collect();
+
+        let mut useful: Vec<usize> = (0..valves.len())
+            .filter(|&ix| valves[ix].flow > 0)
+            .collect();
+        useful.push(index[START]);
+
+        let distances = useful
+            .iter()
+            .map(|&from| {
+                let search = search::bfs([from], |&ix| tunnels[ix].clone(), |_| false);
+                useful
+                    .iter()
+                    .map(|to| search.cost_to(to).map_or(u32::MAX, |d| d as u32))
+                    .collect()
+            })
+            .collect();
+        Self {
+            flows: useful[..useful.len() - 1]
+                .iter()
+                .map(|&ix| valves[ix].flow)
+                .collect(),
+            distances,
+        }
+    }
+
+    /// The most pressure that can be released in `minutes` for every set of
+    /// valves, as a bitmask of the valves worth opening, that can be opened
+    /// in time.
+    pub fn best_per_set(&self, minutes: u32) -> HashMap<u64, u32> {
+        let mut best = HashMap::new();
+        self.explore(self.flows.len(), minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn explore(
+        &self,
+        at: usize,
+        minutes_left: u32,
+        opened: u64,
+        pressure: u32,
+        best: &mut HashMap<u64, u32>,
+    ) {
+        let entry = best.entry(opened).or_default();
+        *entry = pressure.max(*entry);
+        for next in 0..self.flows.len() {
+            let cost = self.distances[at][next].saturating_add(1);
+            if opened & (1 << next) != 0 || cost >= minutes_left {
+                continue;
+            }
+            let left = minutes_left - cost;
+            self.explore(
+                next,
+                left,
+                opened | (1 << next),
+                pressure + self.flows[next] * left,
+                best,
+            );
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Network;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map(|valves| Network::new(&valves))
+    }
+
+    fn part1(&self, network: &Self::Input) -> PartResult {
+        Ok(run_part1(network).into())
+    }
+
+    fn part2(&self, network: &Self::Input) -> PartResult {
+        Ok(run_part2(network).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Valve>, ParseError> {
+    let re =
+        Regex::new(r"^Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (.+)$").unwrap();
+    let mut valves: Vec<Valve> = Vec::new();
+    let mut tunnel_spans = Vec::new();
+    for line in input.lines() {
+        let captures = re
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "Invalid valve"))?;
+        let name = captures.get(1).unwrap().as_str();
+        if valves.iter().any(|valve| valve.name == name) {
+            return Err(ParseError::at(input, name, "Duplicate valve"));
+        }
+        let flow = captures.get(2).unwrap().as_str();
+        let tunnels: Vec<_> = captures.get(3).unwrap().as_str().split(", ").collect();
+        valves.push(Valve {
+            name: name.to_string(),
+            flow: flow
+                .parse()
+                .map_err(|_| ParseError::at(input, flow, "Invalid flow rate"))?,
+            tunnels: tunnels.iter().map(|t| t.to_string()).collect(),
+        });
+        tunnel_spans.extend(tunnels);
+    }
+
+    if !valves.iter().any(|valve| valve.name == START) {
+        return Err(ParseError::at(input, input, format!("No valve {START}")));
+    }
+    if valves.iter().filter(|valve| valve.flow > 0).count() > 64 {
+        return Err(ParseError::at(
+            input,
+            input,
+            "More than 64 valves with a flow rate",
+        ));
+    }
+    for tunnel in tunnel_spans {
+        if !valves.iter().any(|valve| valve.name == tunnel) {
+            return Err(ParseError::at(input, tunnel, "Unknown valve"));
+        }
+    }
+    Ok(valves)
+}
+
+pub fn run_part1(network: &Network) -> u32 {
+    *network.best_per_set(30).values().max().unwrap()
+}
+
+/// You and the elephant open disjoint sets of valves.
+pub fn run_part2(network: &Network) -> u32 {
+    let mut sets: Vec<_> = network.best_per_set(26).into_iter().collect();
+    sets.sort_by_key(|&(_, pressure)| Reverse(pressure));
+    let mut best = 0;
+    for (ix, &(mine, pressure)) in sets.iter().enumerate() {
+        if pressure * 2 < best {
+            break;
+        }
+        for &(elephants, other) in &sets[ix..] {
+            if pressure + other <= best {
+                break;
+            }
+            if mine & elephants == 0 {
+                best = pressure + other;
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    fn network() -> Network {
+        Network::new(&parse(INPUT_TEST).unwrap())
+    }
+
+    #[test]
+    fn test_parse() {
+        let valves = parse(INPUT_TEST).unwrap();
+        assert_eq!(valves.len(), 10);
+        assert_eq!(
+            valves[7],
+            Valve {
+                name: "HH".to_string(),
+                flow: 22,
+                tunnels: vec!["GG".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let network = network();
+        // BB, CC, DD, EE, HH and JJ, then AA
+        assert_eq!(network.flows, vec![13, 2, 20, 3, 22, 21]);
+        assert_eq!(network.distances[6], vec![1, 2, 1, 2, 5, 2, 0]);
+        assert_eq!(network.distances[4][5], 7);
+    }
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&network()), 1651);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&network()), 1707);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Valve AA has flow rate=0; tunnel leads to valve BB").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 49, "BB"));
+        let err =
+            parse("Valve AA has flow rate=0; tunnel leads to valve AA\nValve BB").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse(
+            "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+             Valve BB has flow rate=1; tunnel leads to valve AA\n\
+             Valve BB has flow rate=2; tunnel leads to valve AA",
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 7, "BB"));
+        assert_eq!(err.message, "Duplicate valve");
+    }
+}
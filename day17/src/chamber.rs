@@ -0,0 +1,158 @@
+use common::ParseError;
+
+/// Rows are stored one bit per column in a `u8`.
+pub const MAX_WIDTH: u32 = 8;
+
+const DEFAULT: &str = include_str!("../chamber.txt");
+
+/// The rules of the simulation: how wide the chamber is, which rocks fall in
+/// turn and where they appear.
+///
+/// The text format starts with `width: <columns>` and
+/// `spawn: <columns from the left wall> <rows above the tower>`, followed by
+/// the rocks drawn with `#` and `.`, each after a blank line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chamber {
+    pub width: u32,
+    pub spawn_x: u32,
+    pub spawn_y: usize,
+    /// Rocks as bitmasks, one byte per row from the bottom up, with bit `x`
+    /// set for column `x` when the rock is against the left wall.
+    pub rocks: Vec<Vec<u8>>,
+}
+
+impl Chamber {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let mut width = None;
+        let mut spawn = None;
+        for line in lines.by_ref().take_while(|line| !line.trim().is_empty()) {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::at(input, line, "Expected '<setting>: <value>'"))?;
+            let value = value.trim();
+            match key.trim() {
+                "width" => width = Some(parse_width(input, value)?),
+                "spawn" => {
+                    let (x, y) = value
+                        .split_once(' ')
+                        .and_then(|(x, y)| Some((x.parse().ok()?, y.trim().parse().ok()?)))
+                        .ok_or_else(|| ParseError::at(input, value, "Expected '<x> <y>'"))?;
+                    spawn = Some((x, y, value));
+                }
+                _ => return Err(ParseError::at(input, key, "Unknown setting")),
+            }
+        }
+        let width = width.ok_or_else(|| ParseError::at(input, input, "Missing width"))?;
+        let (spawn_x, spawn_y, spawn) =
+            spawn.ok_or_else(|| ParseError::at(input, input, "Missing spawn"))?;
+        if spawn_x >= width {
+            return Err(ParseError::at(input, spawn, "Spawn outside the chamber"));
+        }
+
+        let mut rocks = Vec::new();
+        loop {
+            let drawing: Vec<_> = lines
+                .by_ref()
+                .skip_while(|line| line.trim().is_empty())
+                .take_while(|line| !line.trim().is_empty())
+                .collect();
+            if drawing.is_empty() {
+                break;
+            }
+            rocks.push(parse_rock(input, &drawing, width - spawn_x)?);
+        }
+        if rocks.is_empty() {
+            return Err(ParseError::at(input, input, "No rocks"));
+        }
+
+        Ok(Self {
+            width,
+            spawn_x,
+            spawn_y,
+            rocks,
+        })
+    }
+
+    /// A row with every column filled.
+    pub fn full_row(&self) -> u8 {
+        u8::MAX >> (MAX_WIDTH - self.width)
+    }
+}
+
+impl Default for Chamber {
+    /// The chamber from the puzzle.
+    fn default() -> Self {
+        Self::parse(DEFAULT).unwrap()
+    }
+}
+
+fn parse_width(input: &str, value: &str) -> Result<u32, ParseError> {
+    let width: u32 = value
+        .parse()
+        .map_err(|_| ParseError::at(input, value, "Invalid width"))?;
+    if width == 0 || width > MAX_WIDTH {
+        return Err(ParseError::at(
+            input,
+            value,
+            format!("Width must be between 1 and {MAX_WIDTH}"),
+        ));
+    }
+    Ok(width)
+}
+
+/// Parses a rock drawn top row first, which must fit in `room` columns.
+fn parse_rock(input: &str, drawing: &[&str], room: u32) -> Result<Vec<u8>, ParseError> {
+    let mut rock = Vec::with_capacity(drawing.len());
+    for line in drawing.iter().rev() {
+        let mut bits = 0;
+        for (x, c) in line.trim_end().char_indices() {
+            match c {
+                '#' if (x as u32) < room => bits |= 1 << x,
+                '#' => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "Rock does not fit in the chamber",
+                    ))
+                }
+                '.' => {}
+                _ => {
+                    let span = &line[x..x + c.len_utf8()];
+                    return Err(ParseError::at(input, span, "Unexpected character"));
+                }
+            }
+        }
+        rock.push(bits);
+    }
+    if rock.iter().all(|&bits| bits == 0) {
+        return Err(ParseError::at(input, drawing[0], "Empty rock"));
+    }
+    Ok(rock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let chamber = Chamber::default();
+        assert_eq!((chamber.width, chamber.spawn_x, chamber.spawn_y), (7, 2, 3));
+        assert_eq!(chamber.rocks.len(), 5);
+        assert_eq!(chamber.rocks[2], vec![0b111, 0b100, 0b100]);
+        assert_eq!(chamber.full_row(), 0b111_1111);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Chamber::parse("width: 9\nspawn: 2 3\n\n#\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 8, "9"));
+        let err = Chamber::parse("width: 4\nspawn: 2 3\n\n##\n\n###\n").unwrap_err();
+        assert_eq!((err.line, err.column), (6, 1));
+        let err = Chamber::parse("width: 4\nspawn: 0 3\n\n#x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 2, "x"));
+        let err = Chamber::parse("width: 4\nspawn: 0 3\n").unwrap_err();
+        assert_eq!(err.message, "No rocks");
+    }
+}
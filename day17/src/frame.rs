@@ -0,0 +1,177 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, Write},
+    ops::Range,
+};
+
+use crate::{Chamber, Tower};
+
+/// A rock on its way down, with its bottom-left corner at column `x` and
+/// height `y` above the floor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FallingRock {
+    pub rock_ix: usize,
+    pub x: u32,
+    pub y: usize,
+}
+
+/// What just happened to the falling rock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Appear,
+    Push { dx: i8, moved: bool },
+    Fall { rest: bool },
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Step::Appear => write!(f, "A new rock begins falling"),
+            Step::Push { dx, moved } => {
+                let direction = if dx < 0 { "left" } else { "right" };
+                write!(f, "Jet of gas pushes rock {direction}")?;
+                if !moved {
+                    write!(f, ", but nothing happens")?;
+                }
+                Ok(())
+            }
+            Step::Fall { rest } => {
+                write!(f, "Rock falls 1 unit")?;
+                if rest {
+                    write!(f, ", causing it to come to rest")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Tower {
+    /// Draws the rows still kept in memory as the puzzle does, with the
+    /// falling rock as `@` and settled rock as `#`. The bottom is `+-----+`
+    /// for the floor and `|~~~~~|` when lower rows were dropped.
+    pub fn render(&self, falling: Option<FallingRock>) -> String {
+        let rock = falling.map(|falling| {
+            let rock = &self.chamber.rocks[falling.rock_ix];
+            (rock, falling.x, falling.y - self.floor)
+        });
+        let top = rock.map_or(self.rows.len(), |(rock, _, y)| {
+            self.rows.len().max(y + rock.len())
+        });
+
+        let width = self.chamber.width;
+        let mut frame = String::new();
+        for y in (0..top).rev() {
+            let settled = self.rows.get(y).copied().unwrap_or(0);
+            let falling = rock.map_or(0, |(rock, x, ry)| {
+                y.checked_sub(ry)
+                    .and_then(|dy| rock.get(dy))
+                    .map_or(0, |bits| bits << x)
+            });
+            frame.push('|');
+            for x in 0..width {
+                frame.push(match (falling & (1 << x) != 0, settled & (1 << x) != 0) {
+                    (true, _) => '@',
+                    (false, true) => '#',
+                    (false, false) => '.',
+                });
+            }
+            frame.push_str("|\n");
+        }
+        let (corner, edge) = if self.floor == 0 {
+            ('+', "-")
+        } else {
+            ('|', "~")
+        };
+        frame.push(corner);
+        frame.push_str(&edge.repeat(width as usize));
+        frame.push(corner);
+        frame
+    }
+}
+
+/// Drops the rocks before `rocks` silently, then writes a frame for every
+/// step of the rocks in `rocks`, which are numbered from 0. The frame where a
+/// rock comes to rest draws it as settled rock. Pass a
+/// [`std::fs::File`] to dump the frames to a file.
+pub fn replay(
+    chamber: &Chamber,
+    jets: &str,
+    rocks: Range<usize>,
+    mut out: impl Write,
+) -> io::Result<()> {
+    let mut tower = Tower::with_chamber(chamber.clone(), jets);
+    while tower.rocks_fallen() < rocks.start {
+        tower.drop_rock();
+    }
+    while tower.rocks_fallen() < rocks.end {
+        let rock = tower.rocks_fallen();
+        let mut result = Ok(());
+        tower.drop_rock_with(|tower, falling, step| {
+            if result.is_ok() {
+                let mut frame = tower.render(Some(falling));
+                if step == (Step::Fall { rest: true }) {
+                    // the puzzle already draws the rock that comes to rest as settled
+                    frame = frame.replace('@', "#");
+                }
+                result = write!(out, "Rock {rock}: {step}:\n{frame}\n\n");
+            }
+        });
+        result?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_replay() {
+        let mut out = Vec::new();
+        replay(&Chamber::default(), INPUT_TEST.trim(), 0..2, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let frames: Vec<_> = out.split("\n\n").collect();
+        assert_eq!(
+            frames[0],
+            "Rock 0: A new rock begins falling:\n\
+             |..@@@@.|\n\
+             |.......|\n\
+             |.......|\n\
+             |.......|\n\
+             +-------+"
+        );
+        assert_eq!(
+            frames[3],
+            "Rock 0: Jet of gas pushes rock right, but nothing happens:\n\
+             |...@@@@|\n\
+             |.......|\n\
+             |.......|\n\
+             +-------+"
+        );
+        assert_eq!(
+            frames[8],
+            "Rock 0: Rock falls 1 unit, causing it to come to rest:\n\
+             |..####.|\n\
+             +-------+"
+        );
+        assert!(frames[9].starts_with("Rock 1: A new rock begins falling:\n|...@...|"));
+    }
+
+    #[test]
+    fn test_render() {
+        let mut tower = Tower::new(INPUT_TEST.trim());
+        tower.drop_rock();
+        tower.drop_rock();
+        assert_eq!(
+            tower.render(None),
+            "|...#...|\n\
+             |..###..|\n\
+             |...#...|\n\
+             |..####.|\n\
+             +-------+"
+        );
+    }
+}
@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+
+use common::{search, ParseError, PartResult, Solution};
+
+mod chamber;
+mod frame;
+pub use chamber::{Chamber, MAX_WIDTH};
+pub use frame::{replay, FallingRock, Step};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, jets: &Self::Input) -> PartResult {
+        Ok(run_part1(jets).into())
+    }
+
+    fn part2(&self, jets: &Self::Input) -> PartResult {
+        Ok(run_part2(jets).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    let jets = input.trim();
+    if jets.is_empty() {
+        return Err(ParseError::at(input, input, "No jets"));
+    }
+    if let Some(ix) = jets.find(|c| c != '<' && c != '>') {
+        let c = jets[ix..].chars().next().unwrap();
+        return Err(ParseError::at(
+            input,
+            &jets[ix..ix + c.len_utf8()],
+            "Invalid jet",
+        ));
+    }
+    Ok(jets.to_string())
+}
+
+const SEAL_INTERVAL: usize = 64;
+
+/// Rocks falling into a chamber, pushed around by the jets.
+pub struct Tower {
+    chamber: Chamber,
+    /// Column shift of each jet.
+    jets: Vec<i8>,
+    jet_ix: usize,
+    rocks_fallen: usize,
+    /// Rows of settled rock from the bottom up, starting at `floor`. Rows
+    /// that rocks can no longer reach are dropped once the tower has grown by
+    /// [`SEAL_INTERVAL`] rows.
+    rows: Vec<u8>,
+    floor: usize,
+    next_seal: usize,
+}
+
+impl Tower {
+    pub fn new(jets: &str) -> Self {
+        Self::with_chamber(Chamber::default(), jets)
+    }
+
+    pub fn with_chamber(chamber: Chamber, jets: &str) -> Self {
+        Self {
+            chamber,
+            jets: jets
+                .bytes()
+                .map(|jet| if jet == b'<' { -1 } else { 1 })
+                .collect(),
+            jet_ix: 0,
+            rocks_fallen: 0,
+            rows: Vec::new(),
+            floor: 0,
+            next_seal: SEAL_INTERVAL,
+        }
+    }
+
+    pub fn chamber(&self) -> &Chamber {
+        &self.chamber
+    }
+
+    /// The rows still kept in memory, from the bottom up.
+    pub fn rows(&self) -> &[u8] {
+        &self.rows
+    }
+
+    pub fn height(&self) -> i64 {
+        (self.floor + self.rows.len()) as i64
+    }
+
+    pub fn rocks_fallen(&self) -> usize {
+        self.rocks_fallen
+    }
+
+    /// Drops the next rock until it settles.
+    pub fn drop_rock(&mut self) {
+        self.drop_rock_with(|_, _, _| {});
+    }
+
+    /// Drops the next rock until it settles, calling `on_step` with the tower
+    /// and the falling rock when it appears and after every move.
+    pub fn drop_rock_with(&mut self, mut on_step: impl FnMut(&Tower, FallingRock, Step)) {
+        let rock_ix = self.rocks_fallen % self.chamber.rocks.len();
+        let mut x = self.chamber.spawn_x;
+        let mut y = self.rows.len() + self.chamber.spawn_y;
+        let floor = self.floor;
+        let falling = |x, y| FallingRock {
+            rock_ix,
+            x,
+            y: floor + y,
+        };
+        on_step(self, falling(x, y), Step::Appear);
+        loop {
+            let rock = &self.chamber.rocks[rock_ix];
+
+            // pushed by jet
+            let dx = self.jets[self.jet_ix];
+            self.jet_ix = (self.jet_ix + 1) % self.jets.len();
+            let pushed = x.checked_add_signed(dx as i32);
+            let moved = pushed.is_some_and(|pushed| !self.collides(rock, pushed, y));
+            if moved {
+                x = pushed.unwrap();
+            }
+            on_step(self, falling(x, y), Step::Push { dx, moved });
+
+            // fall down
+            let rest = y == 0 || self.collides(rock, x, y - 1);
+            if !rest {
+                y -= 1;
+            }
+            on_step(self, falling(x, y), Step::Fall { rest });
+            if rest {
+                break;
+            }
+        }
+        self.settle(rock_ix, x, y);
+        self.rocks_fallen += 1;
+    }
+
+    /// Whether `rock` at column `x` and row `y` (relative to the floor) hits
+    /// a wall or settled rock.
+    fn collides(&self, rock: &[u8], x: u32, y: usize) -> bool {
+        rock.iter().enumerate().any(|(dy, &bits)| {
+            let bits = (bits as u16) << x;
+            bits > self.chamber.full_row() as u16
+                || self
+                    .rows
+                    .get(y + dy)
+                    .is_some_and(|&row| row as u16 & bits != 0)
+        })
+    }
+
+    fn settle(&mut self, rock_ix: usize, x: u32, y: usize) {
+        let rock = &self.chamber.rocks[rock_ix];
+        if self.rows.len() < y + rock.len() {
+            self.rows.resize(y + rock.len(), 0);
+        }
+        for (dy, &bits) in rock.iter().enumerate() {
+            self.rows[y + dy] |= bits << x;
+        }
+        if self.rows.len() >= self.next_seal {
+            self.seal_floor();
+        }
+    }
+
+    fn is_rock(&self, x: u32, y: usize) -> bool {
+        self.rows.get(y).is_some_and(|row| row & (1 << x) != 0)
+    }
+
+    /// Drops the rows that no falling rock can reach any more. Every cell a
+    /// rock passes through is connected to the space above the tower, so the
+    /// lowest such cell bounds the rows a rock can still touch.
+    fn seal_floor(&mut self) {
+        let top = self.rows.len();
+        let open = search::bfs(
+            (0..self.chamber.width).map(|x| (x, top)),
+            |&(x, y)| {
+                [
+                    (x.wrapping_sub(1), y),
+                    (x + 1, y),
+                    (x, y.wrapping_sub(1)),
+                    (x, y + 1),
+                ]
+                .into_iter()
+                .filter(|&(x, y)| x < self.chamber.width && y <= top && !self.is_rock(x, y))
+            },
+            |_| false,
+        );
+        let lowest = open.visited().map(|&(_, y)| y).min().unwrap_or(top);
+        // the row below the lowest open cell still blocks rocks
+        let sealed = lowest.saturating_sub(1);
+        self.rows.drain(..sealed);
+        self.floor += sealed;
+        self.next_seal = self.rows.len() + SEAL_INTERVAL;
+    }
+
+    /// Depth of the top cell of every column below the height of the tower,
+    /// capped so that the profile ignores what rocks can no longer reach.
+    fn surface(&self) -> [usize; MAX_WIDTH as usize] {
+        const MAX_DEPTH: usize = 64;
+        let mut surface = [MAX_DEPTH; MAX_WIDTH as usize];
+        for (depth, row) in self.rows.iter().rev().take(MAX_DEPTH).enumerate() {
+            for (x, top) in surface.iter_mut().enumerate() {
+                if row & (1 << x) != 0 {
+                    *top = (*top).min(depth);
+                }
+            }
+        }
+        surface
+    }
+}
+
+/// Height of the tower after `rocks` rocks. Once the next rock, the next jet
+/// and the surface of the tower repeat, whole cycles are skipped.
+pub fn tower_height(chamber: &Chamber, jets: &str, rocks: usize) -> i64 {
+    let mut tower = Tower::with_chamber(chamber.clone(), jets);
+    let mut seen = HashMap::new();
+    let mut skipped_height = 0;
+    while tower.rocks_fallen < rocks {
+        tower.drop_rock();
+        if skipped_height != 0 {
+            continue;
+        }
+        let key = (
+            tower.rocks_fallen % chamber.rocks.len(),
+            tower.jet_ix,
+            tower.surface(),
+        );
+        if let Some((rocks_before, height_before)) =
+            seen.insert(key, (tower.rocks_fallen, tower.height()))
+        {
+            let cycle_rocks = tower.rocks_fallen - rocks_before;
+            let cycles = (rocks - tower.rocks_fallen) / cycle_rocks;
+            skipped_height = cycles as i64 * (tower.height() - height_before);
+            tower.rocks_fallen += cycles * cycle_rocks;
+        }
+    }
+    tower.height() + skipped_height
+}
+
+pub fn run_part1(jets: &str) -> i64 {
+    tower_height(&Chamber::default(), jets, 2022)
+}
+
+pub fn run_part2(jets: &str) -> i64 {
+    tower_height(&Chamber::default(), jets, 1_000_000_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input.txt");
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(INPUT_TEST), 3068);
+    }
+
+    #[test]
+    fn test_input_part1_real() {
+        assert_eq!(run_part1(INPUT), 3224);
+    }
+
+    fn drop_rocks(jets: &str, n: usize) -> Tower {
+        drop_rocks_in(Chamber::default(), jets, n)
+    }
+
+    fn drop_rocks_in(chamber: Chamber, jets: &str, n: usize) -> Tower {
+        let mut tower = Tower::with_chamber(chamber, jets);
+        for _ in 0..n {
+            tower.drop_rock();
+        }
+        tower
+    }
+
+    #[test]
+    fn test_input_part1_2_blocks() {
+        let tower = drop_rocks(INPUT_TEST, 2);
+        assert_eq!(
+            tower.render(None),
+            "|...#...|\n\
+             |..###..|\n\
+             |...#...|\n\
+             |..####.|\n\
+             +-------+"
+        );
+        assert_eq!(tower.height(), 4);
+    }
+
+    #[test]
+    fn test_input_part1_3_blocks() {
+        let tower = drop_rocks(INPUT_TEST, 3);
+        assert_eq!(
+            tower.render(None),
+            "|..#....|\n\
+             |..#....|\n\
+             |####...|\n\
+             |..###..|\n\
+             |...#...|\n\
+             |..####.|\n\
+             +-------+"
+        );
+        assert_eq!(tower.height(), 6);
+    }
+
+    #[test]
+    fn test_unreachable_rows_are_dropped() {
+        let tower = drop_rocks(INPUT_TEST, 5000);
+        assert!(tower.rows().len() < 2 * SEAL_INTERVAL);
+        assert_eq!(tower.height(), 7577);
+    }
+
+    #[test]
+    fn test_tower_height_matches_simulation() {
+        for rocks in [0, 1, 100, 5000] {
+            let tower = drop_rocks(INPUT_TEST, rocks);
+            assert_eq!(
+                tower_height(tower.chamber(), INPUT_TEST, rocks),
+                tower.height()
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom_chamber() {
+        // a single 1x1 rock stacks up in the spawn column of a 1-wide chamber
+        let chamber = Chamber::parse("width: 1\nspawn: 0 3\n\n#\n").unwrap();
+        assert_eq!(tower_height(&chamber, "<>", 1_000_000), 1_000_000);
+
+        // an 8-wide chamber uses every bit of a row
+        let chamber = Chamber::parse("width: 8\nspawn: 0 0\n\n########\n").unwrap();
+        let mut tower = Tower::with_chamber(chamber, ">");
+        tower.drop_rock();
+        assert_eq!(tower.rows()[0], u8::MAX);
+        // hollow shapes leave holes that the flood fill has to see through
+        let chamber = Chamber::parse("width: 5\nspawn: 1 3\n\n###\n#.#\n\n#\n").unwrap();
+        for rocks in [10, 1000] {
+            let tower = drop_rocks_in(chamber.clone(), "<<>><>", rocks);
+            assert_eq!(tower_height(&chamber, "<<>><>", rocks), tower.height());
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(">>><<x<>").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 6, "x"));
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(INPUT_TEST), 1514285714288);
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use common::{
+    parse_lines,
+    search::{self, Search},
+    ParseError, PartResult, Solution,
+};
+
+const STEPS: [(i32, i32, i32); 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+];
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<(i32, i32, i32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        build_cubes_set(input)
+    }
+
+    fn part1(&self, cubes: &Self::Input) -> PartResult {
+        Ok(run_part1(cubes).into())
+    }
+
+    fn part2(&self, cubes: &Self::Input) -> PartResult {
+        Ok(run_part2(cubes).into())
+    }
+}
+
+pub fn build_cubes_set(input: &str) -> Result<HashSet<(i32, i32, i32)>, ParseError> {
+    let cubes = parse_lines(input, |line| {
+        let coords = line
+            .split(',')
+            .map(|c| {
+                c.parse::<i32>()
+                    .map_err(|_| ParseError::at(line, c, "Invalid coordinate"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        match coords[..] {
+            [x, y, z] => Ok((x, y, z)),
+            _ => Err(ParseError::at(line, line, "Expected three coordinates")),
+        }
+    })?;
+    Ok(cubes.into_iter().collect())
+}
+
+fn build_external_set(cubes: &HashSet<(i32, i32, i32)>) -> Search<(i32, i32, i32), usize> {
+    let (bb_min, bb_max) = bounding_box(cubes);
+    let bb_min = (bb_min.0 - 1, bb_min.1 - 1, bb_min.2 - 1);
+    let bb_max = (bb_max.0 + 1, bb_max.1 + 1, bb_max.2 + 1);
+    let start = bb_min;
+    assert!(!cubes.contains(&bb_min)); // starting point must be outside of the droplet
+    search::bfs(
+        [start],
+        |&(x, y, z)| {
+            STEPS
+                .iter()
+                .map(move |(dx, dy, dz)| (x + dx, y + dy, z + dz))
+                .filter(|p @ (x, y, z)| {
+                    *x >= bb_min.0
+                        && *x <= bb_max.0
+                        && *y >= bb_min.1
+                        && *y <= bb_max.1
+                        && *z >= bb_min.2
+                        && *z <= bb_max.2
+                        && !cubes.contains(p)
+                })
+        },
+        |_| false,
+    )
+}
+
+fn bounding_box(cubes: &HashSet<(i32, i32, i32)>) -> ((i32, i32, i32), (i32, i32, i32)) {
+    cubes.iter().fold(
+        (
+            (i32::MAX, i32::MAX, i32::MAX),
+            (i32::MIN, i32::MIN, i32::MIN),
+        ),
+        |((x_min, y_min, z_min), (x_max, y_max, z_max)), (x, y, z)| {
+            (
+                (x_min.min(*x), y_min.min(*y), z_min.min(*z)),
+                (x_max.max(*x), y_max.max(*y), z_max.max(*z)),
+            )
+        },
+    )
+}
+
+pub fn run_part1(cubes: &HashSet<(i32, i32, i32)>) -> usize {
+    let mut surface_area = 0;
+    for (x, y, z) in cubes {
+        for (dx, dy, dz) in STEPS {
+            if !cubes.contains(&(x + dx, y + dy, z + dz)) {
+                surface_area += 1;
+            }
+        }
+    }
+
+    surface_area
+}
+
+pub fn run_part2(cubes: &HashSet<(i32, i32, i32)>) -> usize {
+    let external = build_external_set(cubes);
+
+    let mut surface_area = 0;
+    for (x, y, z) in cubes {
+        for (dx, dy, dz) in STEPS {
+            let p = (x + dx, y + dy, z + dz);
+            if external.is_visited(&p) {
+                surface_area += 1;
+            }
+        }
+    }
+
+    surface_area
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&build_cubes_set(INPUT_TEST).unwrap()), 64);
+    }
+
+    #[test]
+    fn test_part1_two_cubes() {
+        let input = "1,1,1\n2,1,1";
+        assert_eq!(run_part1(&build_cubes_set(input).unwrap()), 10);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&build_cubes_set(INPUT_TEST).unwrap()), 58);
+    }
+}
@@ -0,0 +1,783 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Formatter},
+    ops::{Index, IndexMut, Sub},
+    sync::atomic::{self, AtomicUsize},
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::{parse_lines, Answer, ParseError, PartResult, Solution};
+use regex::Regex;
+
+/// Resources are kept in fixed-size arrays so that states stay `Copy`.
+pub const MAX_RESOURCES: usize = 8;
+
+/// The resource the puzzle asks to collect.
+pub const GEODE: &str = "geode";
+
+/// An amount of every resource, indexed like [`Blueprint::resources`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+struct Resources([u16; MAX_RESOURCES]);
+
+impl Index<usize> for Resources {
+    type Output = u16;
+
+    fn index(&self, resource: usize) -> &u16 {
+        &self.0[resource]
+    }
+}
+
+impl IndexMut<usize> for Resources {
+    fn index_mut(&mut self, resource: usize) -> &mut u16 {
+        &mut self.0[resource]
+    }
+}
+
+impl PartialOrd for Resources {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut has_less = false;
+        let mut has_greater = false;
+        for (a, b) in self.0.iter().zip(&other.0) {
+            match a.cmp(b) {
+                Ordering::Less => has_less = true,
+                Ordering::Greater => has_greater = true,
+                Ordering::Equal => {}
+            }
+        }
+
+        if has_less && has_greater {
+            None
+        } else if has_greater {
+            Some(Ordering::Greater)
+        } else if has_less {
+            Some(Ordering::Less)
+        } else {
+            Some(Ordering::Equal)
+        }
+    }
+
+    /// Checked for every state in the search, so it stops at the first
+    /// resource that is short.
+    fn ge(&self, other: &Self) -> bool {
+        self.0.iter().zip(&other.0).all(|(a, b)| a >= b)
+    }
+}
+
+impl Sub for Resources {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|resource| {
+            self[resource] - rhs[resource]
+        }))
+    }
+}
+
+/// The resources named in a blueprint and the robots that collect them.
+/// Robots are identified by the resource they collect, and you start with
+/// one of the robot listed first.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Blueprint {
+    /// Resource names in order of first mention.
+    resources: Vec<String>,
+    /// The resource each robot collects and what it costs, in the order of
+    /// the blueprint.
+    robots: Vec<(usize, Resources)>,
+}
+
+impl Blueprint {
+    pub fn resources(&self) -> &[String] {
+        &self.resources
+    }
+
+    pub fn resource(&self, name: &str) -> Option<usize> {
+        self.resources.iter().position(|resource| resource == name)
+    }
+
+    fn cost(&self, robot: usize) -> Option<&Resources> {
+        self.robots
+            .iter()
+            .find(|&&(collects, _)| collects == robot)
+            .map(|(_, cost)| cost)
+    }
+}
+
+/// When to start building each robot, as 1-based minutes, and how much of
+/// the objective that collects by the end. Robots are given by the resource
+/// they collect.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    pub minutes: u16,
+    pub collected: u16,
+    pub builds: Vec<(u16, usize)>,
+}
+
+impl Schedule {
+    /// Tells minute by minute what the robots do, as the puzzle does.
+    pub fn narrate(&self, blueprint: &Blueprint) -> String {
+        let mut state = State::initial(blueprint);
+        let mut minutes = Vec::new();
+        for minute in 1..=self.minutes {
+            let mut lines = vec![format!("== Minute {minute} ==")];
+            let robot = self
+                .builds
+                .iter()
+                .find(|&&(start, _)| start == minute)
+                .map(|&(_, robot)| robot);
+            if let Some(robot) = robot {
+                let cost = blueprint.cost(robot).expect("robot not in blueprint");
+                let amounts: Vec<_> = blueprint
+                    .resources
+                    .iter()
+                    .enumerate()
+                    .filter(|&(resource, _)| cost[resource] > 0)
+                    .map(|(resource, name)| format!("{} {name}", cost[resource]))
+                    .collect();
+                let name = robot_name(&blueprint.resources[robot]);
+                lines.push(format!(
+                    "Spend {} to start building {} {name}.",
+                    amounts.join(" and "),
+                    article(&name),
+                ));
+                state.resources = state.resources - *cost;
+            }
+
+            let collected = state.collect(1);
+            for (resource, name) in blueprint.resources.iter().enumerate() {
+                let robots = state.robots[resource];
+                if robots > 0 {
+                    lines.push(collecting(name, robots, collected.resources[resource]));
+                }
+            }
+            state.resources = collected.resources;
+
+            if let Some(robot) = robot {
+                state.robots[robot] += 1;
+                lines.push(format!(
+                    "The new {} is ready; you now have {} of them.",
+                    robot_name(&blueprint.resources[robot]),
+                    state.robots[robot]
+                ));
+            }
+            minutes.push(lines.join("\n"));
+        }
+        minutes.join("\n\n")
+    }
+}
+
+/// The puzzle's robots crack geodes and collect everything else.
+fn robot_name(resource: &str) -> String {
+    if resource == GEODE {
+        "geode-cracking robot".to_string()
+    } else {
+        format!("{resource}-collecting robot")
+    }
+}
+
+fn article(name: &str) -> &'static str {
+    if name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+fn collecting(resource: &str, robots: u16, total: u16) -> String {
+    let name = robot_name(resource);
+    let s = plural(robots);
+    if resource == GEODE {
+        let verb = if robots == 1 { "cracks" } else { "crack" };
+        format!(
+            "{robots} {name}{s} {verb} {robots} geode{s}; you now have {total} open geode{}.",
+            plural(total)
+        )
+    } else {
+        let verb = if robots == 1 { "collects" } else { "collect" };
+        format!("{robots} {name}{s} {verb} {robots} {resource}; you now have {total} {resource}.")
+    }
+}
+
+fn plural(n: u16) -> &'static str {
+    if n == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct State {
+    /// Robots by the resource they collect.
+    robots: Resources,
+    resources: Resources,
+}
+
+impl State {
+    fn initial(blueprint: &Blueprint) -> Self {
+        let mut robots = Resources::default();
+        robots[blueprint.robots[0].0] = 1;
+        Self {
+            robots,
+            resources: Resources::default(),
+        }
+    }
+
+    /// The state after collecting for `minutes` minutes without building.
+    fn collect(&self, minutes: u16) -> Self {
+        let mut next = self.clone();
+        for resource in 0..MAX_RESOURCES {
+            next.resources[resource] += self.robots[resource] * minutes;
+        }
+        next
+    }
+
+    /// Minutes of collecting before `cost` can be paid, if it ever can.
+    fn minutes_until_affordable(&self, cost: &Resources) -> Option<u16> {
+        let mut wait = 0;
+        for resource in 0..MAX_RESOURCES {
+            match cost[resource].checked_sub(self.resources[resource]) {
+                None | Some(0) => {}
+                Some(_) if self.robots[resource] == 0 => return None,
+                Some(missing) => wait = wait.max(missing.div_ceil(self.robots[resource])),
+            }
+        }
+        Some(wait)
+    }
+
+    fn build(&self, robot: usize, cost: &Resources) -> Self {
+        let mut next = Self {
+            robots: self.robots,
+            resources: self.resources - *cost,
+        };
+        next.robots[robot] += 1;
+        next
+    }
+}
+
+/// Solution for day 19; blueprints are evaluated for the most of `objective`
+/// they can collect on `threads` threads, or on one per CPU if it is 0.
+pub struct Day19 {
+    pub threads: usize,
+    pub objective: &'static str,
+}
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, blueprints: &Self::Input) -> PartResult {
+        let (answer, evaluations) = run_part1(blueprints, self.objective, self.threads)?;
+        Ok(Answer::from(answer).with_details(report(blueprints, &evaluations, self.objective)))
+    }
+
+    fn part2(&self, blueprints: &Self::Input) -> PartResult {
+        let (answer, evaluations) = run_part2(blueprints, self.objective, self.threads)?;
+        Ok(Answer::from(answer).with_details(report(blueprints, &evaluations, self.objective)))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let line_re = Regex::new(r"^Blueprint \d+: (.+)$").unwrap();
+    let robot_re = Regex::new(r"^Each (\w+) robot costs (.+)$").unwrap();
+    parse_lines(input, |line| parse_line(&line_re, &robot_re, line))
+}
+
+fn parse_line(line_re: &Regex, robot_re: &Regex, line: &str) -> Result<Blueprint, ParseError> {
+    let captures = line_re
+        .captures(line)
+        .ok_or_else(|| ParseError::at(line, line, "Invalid blueprint"))?;
+    let mut blueprint = Blueprint {
+        resources: Vec::new(),
+        robots: Vec::new(),
+    };
+    for sentence in captures.get(1).unwrap().as_str().split_terminator('.') {
+        let sentence = sentence.trim();
+        let captures = robot_re
+            .captures(sentence)
+            .ok_or_else(|| ParseError::at(line, sentence, "Invalid robot"))?;
+        let name = captures.get(1).unwrap().as_str();
+        let robot = resource_index(&mut blueprint, line, name)?;
+        if blueprint.cost(robot).is_some() {
+            return Err(ParseError::at(line, name, "Duplicate robot"));
+        }
+        let cost = parse_cost(&mut blueprint, line, captures.get(2).unwrap().as_str())?;
+        blueprint.robots.push((robot, cost));
+    }
+    Ok(blueprint)
+}
+
+fn parse_cost(blueprint: &mut Blueprint, line: &str, cost: &str) -> Result<Resources, ParseError> {
+    let mut resources = Resources::default();
+    for part in cost.split(" and ") {
+        let (amount, name) = part
+            .split_once(' ')
+            .filter(|(_, name)| name.chars().all(|c| c.is_alphanumeric() || c == '-'))
+            .ok_or_else(|| ParseError::at(line, cost, "Invalid cost"))?;
+        let resource = resource_index(blueprint, line, name)?;
+        resources[resource] = amount
+            .parse()
+            .map_err(|_| ParseError::at(line, amount, "Invalid amount"))?;
+    }
+    Ok(resources)
+}
+
+/// The index of the resource called `name`, adding it if it is new.
+fn resource_index(blueprint: &mut Blueprint, line: &str, name: &str) -> Result<usize, ParseError> {
+    if let Some(resource) = blueprint.resource(name) {
+        return Ok(resource);
+    }
+    if blueprint.resources.len() == MAX_RESOURCES {
+        return Err(ParseError::at(
+            line,
+            name,
+            format!("More than {MAX_RESOURCES} resources"),
+        ));
+    }
+    blueprint.resources.push(name.to_string());
+    Ok(blueprint.resources.len() - 1)
+}
+
+/// Depth-first search over which robot to build next. Every branch waits for
+/// the resources of its robot instead of stepping minute by minute.
+struct Optimiser {
+    /// The robots and their costs in the order to try them: robots for the
+    /// objective first, then the rest from the last in the blueprint, which
+    /// tend to be closer to the objective.
+    robots: Vec<(usize, Resources)>,
+    objective: usize,
+    /// Most that can be spent of each resource in a minute, which is also
+    /// how many robots of each kind are worth having.
+    max_spend: Resources,
+    best: u16,
+    /// Robots built so far on the current branch, and when, for the best
+    /// branch.
+    builds: Vec<(u16, usize)>,
+    best_builds: Vec<(u16, usize)>,
+    minutes: u16,
+    /// Resources seen for the same robots and time left, none of which has
+    /// at least as much of everything as another.
+    seen: HashMap<(Resources, u16), Vec<Resources>>,
+}
+
+impl Optimiser {
+    fn new(blueprint: &Blueprint, objective: usize, minutes: u16) -> Self {
+        let mut robots = blueprint.robots.clone();
+        robots.reverse();
+        robots.sort_by_key(|&(robot, _)| robot != objective);
+        let max_spend = Resources(std::array::from_fn(|resource| {
+            robots.iter().map(|(_, cost)| cost[resource]).max().unwrap()
+        }));
+        Self {
+            robots,
+            objective,
+            max_spend,
+            best: 0,
+            builds: Vec::new(),
+            best_builds: Vec::new(),
+            minutes,
+            seen: HashMap::new(),
+        }
+    }
+
+    fn explore(&mut self, mut state: State, minutes_left: u16) {
+        let objective = self.objective;
+        let collected = state.resources[objective] + state.robots[objective] * minutes_left;
+        if collected > self.best {
+            self.best = collected;
+            self.best_builds.clone_from(&self.builds);
+        }
+        // at best, a new objective robot is ready at the end of every minute
+        if collected + minutes_left * minutes_left.saturating_sub(1) / 2 <= self.best {
+            return;
+        }
+
+        // resources that cannot all be spent in time make no difference
+        for resource in (0..MAX_RESOURCES).filter(|&resource| resource != objective) {
+            let spend = self.max_spend[resource].saturating_mul(minutes_left);
+            state.resources[resource] = state.resources[resource].min(spend);
+        }
+        if self.is_dominated(&state, minutes_left) {
+            return;
+        }
+
+        for ix in 0..self.robots.len() {
+            let (robot, cost) = self.robots[ix];
+            if robot != objective && state.robots[robot] >= self.max_spend[robot] {
+                continue;
+            }
+            let Some(wait) = state.minutes_until_affordable(&cost) else {
+                continue;
+            };
+            // the robot has to be ready before the last minute to collect anything
+            if wait + 1 >= minutes_left {
+                continue;
+            }
+            let next = state.collect(wait + 1).build(robot, &cost);
+            let minute = self.minutes - minutes_left + wait + 1;
+            self.builds.push((minute, robot));
+            self.explore(next, minutes_left - wait - 1);
+            self.builds.pop();
+        }
+    }
+
+    fn is_dominated(&mut self, state: &State, minutes_left: u16) -> bool {
+        let seen = self.seen.entry((state.robots, minutes_left)).or_default();
+        if seen.iter().any(|resources| *resources >= state.resources) {
+            return true;
+        }
+        seen.retain(|resources| !state.resources.ge(resources));
+        seen.push(state.resources);
+        false
+    }
+}
+
+/// More minutes than a [`Schedule`] can count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooManyMinutes(pub usize);
+
+impl fmt::Display for TooManyMinutes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} minutes are too many to schedule", self.0)
+    }
+}
+
+impl Error for TooManyMinutes {}
+
+/// A build order that collects the most of `objective` in `minutes` minutes.
+/// Nothing is collected of a resource that the blueprint does not name.
+pub fn optimal_schedule(
+    blueprint: &Blueprint,
+    objective: &str,
+    minutes: usize,
+) -> Result<Schedule, TooManyMinutes> {
+    let minutes = u16::try_from(minutes).map_err(|_| TooManyMinutes(minutes))?;
+    let Some(objective) = blueprint.resource(objective) else {
+        return Ok(Schedule {
+            minutes,
+            collected: 0,
+            builds: Vec::new(),
+        });
+    };
+    let mut optimiser = Optimiser::new(blueprint, objective, minutes);
+    optimiser.explore(State::initial(blueprint), minutes);
+    Ok(Schedule {
+        minutes,
+        collected: optimiser.best,
+        builds: optimiser.best_builds,
+    })
+}
+
+/// The outcome of one blueprint, numbered from 1 as in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Evaluation {
+    pub id: usize,
+    pub schedule: Schedule,
+    pub elapsed: Duration,
+}
+
+/// Finds the most of `objective` each blueprint can collect in `minutes`,
+/// spreading the blueprints over `threads` threads (one per CPU if 0). The
+/// results are in the order of `blueprints`.
+pub fn evaluate(
+    blueprints: &[Blueprint],
+    objective: &str,
+    minutes: usize,
+    threads: usize,
+) -> Result<Vec<Evaluation>, TooManyMinutes> {
+    let threads = match threads {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    let next = AtomicUsize::new(0);
+    let mut evaluations = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.min(blueprints.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let ix = next.fetch_add(1, atomic::Ordering::Relaxed);
+                        let Some(blueprint) = blueprints.get(ix) else {
+                            break done;
+                        };
+                        let start = Instant::now();
+                        let schedule = optimal_schedule(blueprint, objective, minutes);
+                        done.push(schedule.map(|schedule| Evaluation {
+                            id: ix + 1,
+                            schedule,
+                            elapsed: start.elapsed(),
+                        }));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+    evaluations.sort_by_key(|evaluation| evaluation.id);
+    Ok(evaluations)
+}
+
+/// What each blueprint collected and how long it took, followed by its
+/// build order told minute by minute.
+pub fn report(
+    blueprints: &[Blueprint],
+    evaluations: &[Evaluation],
+    objective: &str,
+) -> Vec<String> {
+    evaluations
+        .iter()
+        .flat_map(|evaluation| {
+            let blueprint = &blueprints[evaluation.id - 1];
+            [
+                format!(
+                    "Blueprint {} ends with {} {objective} in {} µs",
+                    evaluation.id,
+                    evaluation.schedule.collected,
+                    evaluation.elapsed.as_micros()
+                ),
+                evaluation.schedule.narrate(blueprint),
+                String::new(),
+            ]
+        })
+        .collect()
+}
+
+/// The sum of the quality levels, with the evaluations it comes from.
+pub fn run_part1(
+    blueprints: &[Blueprint],
+    objective: &str,
+    threads: usize,
+) -> Result<(usize, Vec<Evaluation>), TooManyMinutes> {
+    let evaluations = evaluate(blueprints, objective, 24, threads)?;
+    let quality_levels = evaluations
+        .iter()
+        .map(|evaluation| evaluation.id * evaluation.schedule.collected as usize)
+        .sum();
+    Ok((quality_levels, evaluations))
+}
+
+/// The product of what the first three blueprints collect, with their
+/// evaluations.
+pub fn run_part2(
+    blueprints: &[Blueprint],
+    objective: &str,
+    threads: usize,
+) -> Result<(usize, Vec<Evaluation>), TooManyMinutes> {
+    let first = &blueprints[..blueprints.len().min(3)];
+    let evaluations = evaluate(first, objective, 32, threads)?;
+    let product = evaluations
+        .iter()
+        .map(|evaluation| evaluation.schedule.collected as usize)
+        .product();
+    Ok((product, evaluations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    fn resources(amounts: &[u16]) -> Resources {
+        let mut resources = Resources::default();
+        resources.0[..amounts.len()].copy_from_slice(amounts);
+        resources
+    }
+
+    #[test]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn resources_partial_ord() {
+        let r1 = resources(&[1, 1]);
+        let r2 = resources(&[]);
+        let r3 = resources(&[2]);
+        assert!(r1 >= r2);
+        assert!(!(r3 >= r1));
+        assert!(!(r3 < r1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT_TEST.replacen("costs 3 ore and 14 clay", "costs 3 ore and x clay", 1);
+        let err = parse(&input).err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 107, "x"));
+
+        let input = INPUT_TEST.replacen("Each clay robot", "Each ore robot", 1);
+        let err = parse(&input).err().unwrap();
+        assert_eq!((err.message.as_str(), err.column), ("Duplicate robot", 47));
+
+        let input = INPUT_TEST.replacen("costs 2 ore.", "costs 2 ore and 1 fine clay.", 1);
+        let err = parse(&input).err().unwrap();
+        assert_eq!(
+            (err.message.as_str(), err.text.as_str()),
+            ("Invalid cost", "2 ore and 1 fine clay")
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let blueprints = parse(INPUT_TEST).unwrap();
+        assert_eq!(blueprints.len(), 2);
+        assert_eq!(
+            blueprints[0],
+            Blueprint {
+                resources: ["ore", "clay", "obsidian", "geode"]
+                    .map(String::from)
+                    .to_vec(),
+                robots: vec![
+                    (0, resources(&[4])),
+                    (1, resources(&[2])),
+                    (2, resources(&[3, 14])),
+                    (3, resources(&[2, 0, 7])),
+                ],
+            }
+        );
+        assert_eq!(blueprints[1].cost(3), Some(&resources(&[3, 0, 12])));
+    }
+
+    #[test]
+    fn test_input_part1() {
+        let blueprints = parse(INPUT_TEST).unwrap();
+        let (answer, evaluations) = run_part1(&blueprints, GEODE, 2).unwrap();
+        assert_eq!(answer, 33);
+        let report = report(&blueprints, &evaluations, GEODE);
+        assert_eq!(report.len(), 6);
+        assert!(report[3].starts_with("Blueprint 2 ends with 12 geode in "));
+        assert_eq!(report[4], evaluations[1].schedule.narrate(&blueprints[1]));
+    }
+
+    #[test]
+    fn test_evaluate_order() {
+        let blueprints = parse(INPUT_TEST).unwrap();
+        for threads in [0, 1, 3] {
+            let evaluations = evaluate(&blueprints, GEODE, 24, threads).unwrap();
+            let results: Vec<_> = evaluations
+                .iter()
+                .map(|e| (e.id, e.schedule.collected))
+                .collect();
+            assert_eq!(results, vec![(1, 9), (2, 12)]);
+        }
+    }
+
+    #[test]
+    fn test_narrate() {
+        let blueprint = &parse(INPUT_TEST).unwrap()[0];
+        let [clay, obsidian, geode] =
+            ["clay", "obsidian", GEODE].map(|name| blueprint.resource(name).unwrap());
+        // the build order from the puzzle description
+        let schedule = Schedule {
+            minutes: 24,
+            collected: 9,
+            builds: vec![
+                (3, clay),
+                (5, clay),
+                (7, clay),
+                (11, obsidian),
+                (12, clay),
+                (15, obsidian),
+                (18, geode),
+                (21, geode),
+            ],
+        };
+        let story = schedule.narrate(blueprint);
+        assert!(story.starts_with(
+            "== Minute 1 ==\n\
+             1 ore-collecting robot collects 1 ore; you now have 1 ore.\n\n\
+             == Minute 2 ==\n\
+             1 ore-collecting robot collects 1 ore; you now have 2 ore.\n\n\
+             == Minute 3 ==\n\
+             Spend 2 ore to start building a clay-collecting robot.\n\
+             1 ore-collecting robot collects 1 ore; you now have 1 ore.\n\
+             The new clay-collecting robot is ready; you now have 1 of them.\n\n"
+        ));
+        assert!(story.contains(
+            "== Minute 11 ==\n\
+             Spend 3 ore and 14 clay to start building an obsidian-collecting robot.\n\
+             1 ore-collecting robot collects 1 ore; you now have 2 ore.\n\
+             3 clay-collecting robots collect 3 clay; you now have 4 clay.\n\
+             The new obsidian-collecting robot is ready; you now have 1 of them.\n\n"
+        ));
+        assert!(story.ends_with(
+            "== Minute 24 ==\n\
+             1 ore-collecting robot collects 1 ore; you now have 6 ore.\n\
+             4 clay-collecting robots collect 4 clay; you now have 41 clay.\n\
+             2 obsidian-collecting robots collect 2 obsidian; you now have 8 obsidian.\n\
+             2 geode-cracking robots crack 2 geodes; you now have 9 open geodes."
+        ));
+    }
+
+    #[test]
+    fn test_optimal_schedule() {
+        let blueprint = &parse(INPUT_TEST).unwrap()[1];
+        let schedule = optimal_schedule(blueprint, GEODE, 24).unwrap();
+        assert_eq!(schedule.collected, 12);
+        let story = schedule.narrate(blueprint);
+        assert!(story.ends_with("you now have 12 open geodes."), "{story}");
+    }
+
+    #[test]
+    fn test_other_objectives() {
+        let blueprint = &parse(INPUT_TEST).unwrap()[0];
+        assert_eq!(
+            optimal_schedule(blueprint, "obsidian", 24)
+                .unwrap()
+                .collected,
+            34
+        );
+        assert_eq!(
+            optimal_schedule(blueprint, "diamond", 24)
+                .unwrap()
+                .collected,
+            0
+        );
+        assert_eq!(
+            optimal_schedule(blueprint, GEODE, 70000),
+            Err(TooManyMinutes(70000))
+        );
+    }
+
+    #[test]
+    fn test_modified_rules() {
+        // one more tier on top of geodes, and robots that start with clay
+        let input = "Blueprint 1: Each clay robot costs 1 ore. Each ore robot costs 2 clay. \
+                     Each obsidian robot costs 3 ore and 4 clay. \
+                     Each geode robot costs 2 ore and 3 obsidian. \
+                     Each diamond robot costs 1 clay and 2 geode.";
+        let blueprints = parse(input).unwrap();
+        let blueprint = &blueprints[0];
+        assert_eq!(
+            blueprint.resources(),
+            ["clay", "ore", "obsidian", "geode", "diamond"]
+        );
+        let schedule = optimal_schedule(blueprint, "diamond", 24).unwrap();
+        assert_eq!(schedule.collected, 46);
+        assert!(schedule
+            .narrate(blueprint)
+            .ends_with("you now have 46 diamond."));
+    }
+
+    #[test]
+    fn test_input_part2_blueprint1() {
+        let blueprint = &parse(INPUT_TEST).unwrap()[0];
+        assert_eq!(
+            optimal_schedule(blueprint, GEODE, 32).unwrap().collected,
+            56
+        );
+    }
+
+    #[test]
+    fn test_input_part2_blueprint2() {
+        let blueprint = &parse(INPUT_TEST).unwrap()[1];
+        assert_eq!(
+            optimal_schedule(blueprint, GEODE, 32).unwrap().collected,
+            62
+        );
+    }
+}
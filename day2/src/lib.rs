@@ -1,8 +1,6 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    str::FromStr,
-};
+use std::str::FromStr;
+
+use common::{parse_lines, ParseError, PartResult, Solution};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum Shape {
@@ -22,7 +20,7 @@ impl Shape {
 }
 
 impl FromStr for Shape {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A" => Ok(Shape::Rock),
@@ -31,7 +29,7 @@ impl FromStr for Shape {
             "X" => Ok(Shape::Rock),
             "Y" => Ok(Shape::Paper),
             "Z" => Ok(Shape::Scissors),
-            _ => Err(()),
+            _ => Err(ParseError::at(s, s, "Invalid shape")),
         }
     }
 }
@@ -54,18 +52,18 @@ impl Outcome {
 }
 
 impl FromStr for Outcome {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "X" => Ok(Self::Loss),
             "Y" => Ok(Self::Draw),
             "Z" => Ok(Self::Win),
-            _ => Err(()),
+            _ => Err(ParseError::at(s, s, "Invalid outcome")),
         }
     }
 }
 
-struct Round {
+pub struct Round {
     you: Shape,
     opponent: Shape,
 }
@@ -92,17 +90,28 @@ impl Round {
     }
 }
 
+/// Splits a line into its two columns, for errors to point into the line.
+fn columns(s: &str) -> Result<(&str, &str), ParseError> {
+    s.split_once(' ')
+        .ok_or_else(|| ParseError::at(s, s, "Expected two columns"))
+}
+
+fn parse_column<T: FromStr<Err = ParseError>>(line: &str, column: &str) -> Result<T, ParseError> {
+    T::from_str(column).map_err(|err| ParseError::at(line, column, err.message))
+}
+
 impl FromStr for Round {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let opponent = Shape::from_str(&s[0..1])?;
-        let you = Shape::from_str(&s[2..3])?;
+        let (opponent, you) = columns(s)?;
+        let opponent = parse_column(s, opponent)?;
+        let you = parse_column(s, you)?;
         Ok(Round::new(you, opponent))
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Round2 {
+pub struct Round2 {
     opponent: Shape,
     outcome: Outcome,
 }
@@ -129,50 +138,56 @@ impl Round2 {
 }
 
 impl FromStr for Round2 {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let opponent = Shape::from_str(&s[0..1])?;
-        let outcome = Outcome::from_str(&s[2..3])?;
+        let (opponent, outcome) = columns(s)?;
+        let opponent = parse_column(s, opponent)?;
+        let outcome = parse_column(s, outcome)?;
         Ok(Round2::new(opponent, outcome))
     }
 }
 
-fn run(filename: &str) -> u32 {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-    reader
-        .lines()
-        .into_iter()
-        .map(|line| Round::from_str(&line.unwrap()).unwrap().score())
-        .sum()
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Round, Round2)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> PartResult {
+        Ok(run_part1(rounds).into())
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> PartResult {
+        Ok(run_part2(rounds).into())
+    }
 }
 
-fn run_part2(filename: &str) -> u32 {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-    reader
-        .lines()
-        .into_iter()
-        .filter_map(|line| {
-            let round2 = Round2::from_str(&line.ok()?).ok()?;
-            let round = round2.play();
-            let score = round.score();
-            println!("{:?} you: {:?} score: {}", round2, round.you, score);
-            Some(score)
-        })
-        .sum()
+pub fn parse(input: &str) -> Result<Vec<(Round, Round2)>, ParseError> {
+    parse_lines(input, |line| {
+        Ok((Round::from_str(line)?, Round2::from_str(line)?))
+    })
+}
+
+pub fn run_part1(rounds: &[(Round, Round2)]) -> u32 {
+    rounds.iter().map(|(round, _)| round.score()).sum()
 }
 
-fn main() {
-    let score = run("input.txt");
-    let score2 = run_part2("input.txt");
-    println!("Score: {score} - Score 2: {score2}");
+pub fn run_part2(rounds: &[(Round, Round2)]) -> u32 {
+    rounds
+        .iter()
+        .map(|(_, round2)| round2.play().score())
+        .sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
     #[test]
     fn test_score_shape() {
         assert_eq!(Shape::Rock.score(), 1);
@@ -206,11 +221,17 @@ mod tests {
 
     #[test]
     fn test_run() {
-        assert_eq!(run("input_test.txt"), 15);
+        assert_eq!(run_part1(&parse(INPUT_TEST).unwrap()), 15);
     }
 
     #[test]
     fn test_run_part2() {
-        assert_eq!(run_part2("input_test.txt"), 12);
+        assert_eq!(run_part2(&parse(INPUT_TEST).unwrap()), 12);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("A Y\nB Q\nC Z").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "Q"));
     }
 }
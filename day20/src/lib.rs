@@ -0,0 +1,270 @@
+use common::{parse_lines, ParseError, PartResult, Solution};
+
+mod mixer;
+pub use mixer::Mixer;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> PartResult {
+        Ok(run_part1(numbers).ok_or("No 0 in the file")?.into())
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> PartResult {
+        Ok(run_part2(numbers).ok_or("No 0 in the file")?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_lines(input, |line| {
+        line.parse::<i64>()
+            .map_err(|_| ParseError::at(line, line, "Invalid number"))
+    })
+}
+
+/// The key the real decryption routine multiplies the numbers by.
+pub const DECRYPTION_KEY: i64 = 811589153;
+
+/// Turns the encrypted file into grove coordinates: every number is
+/// multiplied by the key, the list is mixed a number of rounds, and the
+/// numbers at the given offsets after the 0 are summed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decryptor {
+    key: i64,
+    rounds: usize,
+    offsets: Vec<usize>,
+}
+
+impl Default for Decryptor {
+    /// The first decryption attempt of the puzzle: no key and one round.
+    fn default() -> Self {
+        Self {
+            key: 1,
+            rounds: 1,
+            offsets: vec![1000, 2000, 3000],
+        }
+    }
+}
+
+impl Decryptor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn key(mut self, key: i64) -> Self {
+        self.key = key;
+        self
+    }
+
+    pub fn rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    pub fn offsets(mut self, offsets: impl IntoIterator<Item = usize>) -> Self {
+        self.offsets = offsets.into_iter().collect();
+        self
+    }
+
+    /// The list after each round of mixing, starting with the list before
+    /// any. Use [`Mixer::from_zero`] to look at each of them.
+    pub fn mix_rounds(&self, numbers: &[i64]) -> impl Iterator<Item = Mixer> {
+        let mut mixer = self.unmixed(numbers);
+        let rounds = self.rounds;
+        let mut round = 0;
+        std::iter::from_fn(move || {
+            if round > rounds {
+                return None;
+            }
+            if round > 0 {
+                mixer.mix();
+            }
+            round += 1;
+            Some(mixer.clone())
+        })
+    }
+
+    /// The list after all rounds of mixing.
+    pub fn mix(&self, numbers: &[i64]) -> Mixer {
+        let mut mixer = self.unmixed(numbers);
+        for _ in 0..self.rounds {
+            mixer.mix();
+        }
+        mixer
+    }
+
+    fn unmixed(&self, numbers: &[i64]) -> Mixer {
+        let numbers: Vec<_> = numbers.iter().map(|n| n * self.key).collect();
+        Mixer::new(&numbers)
+    }
+
+    /// The sum of the numbers at the offsets from the 0, or `None` if the
+    /// file has no 0.
+    pub fn grove_coordinates(&self, numbers: &[i64]) -> Option<i64> {
+        let mixer = self.mix(numbers);
+        let zero = mixer.zero_position()?;
+        let len = mixer.len();
+        Some(
+            self.offsets
+                .iter()
+                .map(|offset| mixer.get((zero + offset) % len).unwrap().1)
+                .sum(),
+        )
+    }
+}
+
+/// `None` if there is no 0 to count the grove coordinates from.
+pub fn run_part1(numbers: &[i64]) -> Option<i64> {
+    Decryptor::new().grove_coordinates(numbers)
+}
+
+/// `None` if there is no 0 to count the grove coordinates from.
+pub fn run_part2(numbers: &[i64]) -> Option<i64> {
+    Decryptor::new()
+        .key(DECRYPTION_KEY)
+        .rounds(10)
+        .grove_coordinates(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST).unwrap()), Some(3));
+    }
+
+    #[test]
+    fn test_no_zero() {
+        let numbers = parse("1\n2\n-3").unwrap();
+        assert_eq!(run_part1(&numbers), None);
+        let err = Day20.part2(&numbers).unwrap_err();
+        assert_eq!(err.to_string(), "No 0 in the file");
+    }
+
+    /// Mixes the way the puzzle describes it, in O(n²).
+    fn naive_mix(numbers: &mut Vec<(usize, i64)>) {
+        for i in 0..numbers.len() {
+            let ix = numbers
+                .iter()
+                .position(|(orig_ix, _)| *orig_ix == i)
+                .unwrap();
+            let elem = numbers.remove(ix);
+            let len_i64 = numbers.len() as i64;
+            numbers.insert(
+                ((((ix as i64 + elem.1) % len_i64) + len_i64) % len_i64) as usize,
+                elem,
+            );
+        }
+    }
+
+    #[test]
+    fn test_mix_matches_naive() {
+        // values from a small LCG, large enough to wrap several times
+        let mut seed: i64 = 7;
+        let numbers: Vec<i64> = (0..500)
+            .map(|_| {
+                seed = (seed * 1_103_515_245 + 12_345) % 2_147_483_648;
+                seed % 4001 - 2000
+            })
+            .collect();
+        let mut expected: Vec<_> = numbers.iter().copied().enumerate().collect();
+        let mut mixer = Mixer::new(&numbers);
+        for _ in 0..3 {
+            naive_mix(&mut expected);
+            mixer.mix();
+            assert_eq!(mixer.iter().collect::<Vec<_>>(), expected);
+        }
+        for (position, &(orig_ix, value)) in expected.iter().enumerate() {
+            assert_eq!(mixer.position(orig_ix), position);
+            assert_eq!(mixer.get(position), Some((orig_ix, value)));
+        }
+        assert_eq!(mixer.get(expected.len()), None);
+    }
+
+    #[test]
+    fn test_mix_example() {
+        let mut mixer = Mixer::new(&parse(INPUT_TEST).unwrap());
+        mixer.mix();
+        let values: Vec<_> = mixer.iter().map(|(_, value)| value).collect();
+        // the puzzle's 1, 2, -3, 4, 0, 3, -2 with -2 wrapped to the front
+        assert_eq!(values, vec![-2, 1, 2, -3, 4, 0, 3]);
+    }
+
+    #[test]
+    fn test_mix_rounds() {
+        let numbers = parse(INPUT_TEST).unwrap();
+        let decryptor = Decryptor::new().key(DECRYPTION_KEY).rounds(10);
+        let rounds: Vec<Vec<_>> = decryptor
+            .mix_rounds(&numbers)
+            .map(|mixer| mixer.from_zero().collect())
+            .collect();
+        assert_eq!(rounds.len(), 11);
+        // the puzzle shows the rounds starting from the first number instead
+        assert_eq!(
+            rounds[0],
+            vec![
+                0,
+                3246356612,
+                811589153,
+                1623178306,
+                -2434767459,
+                2434767459,
+                -1623178306
+            ]
+        );
+        assert_eq!(
+            rounds[1],
+            vec![
+                0,
+                -2434767459,
+                3246356612,
+                -1623178306,
+                2434767459,
+                1623178306,
+                811589153
+            ]
+        );
+        assert_eq!(
+            rounds[10],
+            vec![
+                0,
+                -2434767459,
+                1623178306,
+                3246356612,
+                -1623178306,
+                2434767459,
+                811589153
+            ]
+        );
+        assert_eq!(
+            decryptor.mix(&numbers).from_zero().collect::<Vec<_>>(),
+            rounds[10]
+        );
+    }
+
+    #[test]
+    fn test_grove_coordinates() {
+        let numbers = parse(INPUT_TEST).unwrap();
+        let decryptor = Decryptor::new().offsets([1, 2, 3]);
+        // 0, 3, -2, 1, ...
+        assert_eq!(decryptor.grove_coordinates(&numbers), Some(2));
+        let decryptor = decryptor.offsets([1000]);
+        assert_eq!(decryptor.grove_coordinates(&numbers), Some(4));
+        assert_eq!(decryptor.grove_coordinates(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST).unwrap()), Some(1623178306));
+    }
+}
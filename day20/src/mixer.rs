@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone, Debug)]
+struct Node {
+    value: i64,
+    priority: u64,
+    /// Number of nodes in the subtree rooted here.
+    size: usize,
+    left: usize,
+    right: usize,
+    parent: usize,
+}
+
+/// The numbers being mixed, kept in an implicit treap: a binary tree ordered
+/// by position in the sequence and balanced by random priorities. Nodes are
+/// indexed by the original position of their number and know their parent,
+/// so a number can be found, removed and reinserted in O(log n).
+#[derive(Clone, Debug)]
+pub struct Mixer {
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+impl Mixer {
+    pub fn new(numbers: &[i64]) -> Self {
+        let mut mixer = Self {
+            nodes: Vec::with_capacity(numbers.len()),
+            root: NIL,
+        };
+        for (orig_ix, &value) in numbers.iter().enumerate() {
+            mixer.nodes.push(Node {
+                value,
+                priority: splitmix64(orig_ix as u64),
+                size: 1,
+                left: NIL,
+                right: NIL,
+                parent: NIL,
+            });
+            mixer.root = mixer.merge(mixer.root, orig_ix);
+        }
+        mixer
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Moves every number, in their original order, as many places forward
+    /// or backward as its value, wrapping around the rest of the sequence.
+    pub fn mix(&mut self) {
+        let len = self.len();
+        if len < 2 {
+            return;
+        }
+        for node in 0..len {
+            let ix = self.position(node);
+            let (left, rest) = self.split(self.root, ix);
+            let (_, right) = self.split(rest, 1);
+            let rest = self.merge(left, right);
+
+            let to = (ix as i64 + self.nodes[node].value).rem_euclid(len as i64 - 1) as usize;
+            let (left, right) = self.split(rest, to);
+            let left = self.merge(left, node);
+            self.root = self.merge(left, right);
+            self.nodes[self.root].parent = NIL;
+        }
+    }
+
+    /// The current position of the number originally at `orig_ix`.
+    pub fn position(&self, orig_ix: usize) -> usize {
+        let mut node = orig_ix;
+        let mut position = self.size(self.nodes[node].left);
+        while self.nodes[node].parent != NIL {
+            let parent = self.nodes[node].parent;
+            if self.nodes[parent].right == node {
+                position += self.size(self.nodes[parent].left) + 1;
+            }
+            node = parent;
+        }
+        position
+    }
+
+    /// The current position of the 0, if there is one.
+    pub fn zero_position(&self) -> Option<usize> {
+        let orig_ix = self.nodes.iter().position(|node| node.value == 0)?;
+        Some(self.position(orig_ix))
+    }
+
+    /// The number at `position`, with its original position.
+    pub fn get(&self, mut position: usize) -> Option<(usize, i64)> {
+        let mut node = self.root;
+        while node != NIL {
+            let left = self.size(self.nodes[node].left);
+            match position.cmp(&left) {
+                Ordering::Less => node = self.nodes[node].left,
+                Ordering::Equal => return Some((node, self.nodes[node].value)),
+                Ordering::Greater => {
+                    position -= left + 1;
+                    node = self.nodes[node].right;
+                }
+            }
+        }
+        None
+    }
+
+    /// The numbers in their current order, with their original positions.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, i64)> + '_ {
+        let mut stack = Vec::new();
+        let mut node = self.root;
+        std::iter::from_fn(move || {
+            while node != NIL {
+                stack.push(node);
+                node = self.nodes[node].left;
+            }
+            let next = stack.pop()?;
+            node = self.nodes[next].right;
+            Some((next, self.nodes[next].value))
+        })
+    }
+
+    /// The numbers once round the circle starting at the 0, or nothing if
+    /// there is no 0.
+    pub fn from_zero(&self) -> impl Iterator<Item = i64> + '_ {
+        let start = self.zero_position();
+        let len = if start.is_some() { self.len() } else { 0 };
+        self.iter()
+            .chain(self.iter())
+            .skip(start.unwrap_or(0))
+            .take(len)
+            .map(|(_, value)| value)
+    }
+
+    fn size(&self, node: usize) -> usize {
+        if node == NIL {
+            0
+        } else {
+            self.nodes[node].size
+        }
+    }
+
+    /// Recomputes the size of `node` and points its children back at it.
+    fn update(&mut self, node: usize) {
+        let Node { left, right, .. } = self.nodes[node];
+        self.nodes[node].size = self.size(left) + self.size(right) + 1;
+        for child in [left, right] {
+            if child != NIL {
+                self.nodes[child].parent = node;
+            }
+        }
+    }
+
+    /// Splits the tree at `node` into its first `count` numbers and the rest.
+    fn split(&mut self, node: usize, count: usize) -> (usize, usize) {
+        if node == NIL {
+            return (NIL, NIL);
+        }
+        let left = self.nodes[node].left;
+        if count <= self.size(left) {
+            let (first, rest) = self.split(left, count);
+            self.nodes[node].left = rest;
+            self.update(node);
+            (first, node)
+        } else {
+            let right = self.nodes[node].right;
+            let (first, rest) = self.split(right, count - self.size(left) - 1);
+            self.nodes[node].right = first;
+            self.update(node);
+            (node, rest)
+        }
+    }
+
+    /// Joins two trees, with the numbers of `left` before those of `right`.
+    fn merge(&mut self, left: usize, right: usize) -> usize {
+        if left == NIL {
+            return right;
+        }
+        if right == NIL {
+            return left;
+        }
+        if self.nodes[left].priority > self.nodes[right].priority {
+            let merged = self.merge(self.nodes[left].right, right);
+            self.nodes[left].right = merged;
+            self.update(left);
+            left
+        } else {
+            let merged = self.merge(left, self.nodes[right].left);
+            self.nodes[right].left = merged;
+            self.update(right);
+            right
+        }
+    }
+}
+
+/// A well-mixed hash of `n`, so that priorities are random but repeatable.
+fn splitmix64(n: u64) -> u64 {
+    let mut z = n.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,492 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use common::{parse_lines, ParseError, PartResult, Solution};
+
+mod linear;
+mod validate;
+pub use linear::{Linear, LinearError, Rational};
+pub use validate::{validate, ValidationError};
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Expr>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> PartResult {
+        Ok(run_part1(monkeys)?.into())
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> PartResult {
+        Ok(run_part2(monkeys)?.into())
+    }
+}
+
+#[derive(Debug)]
+pub enum Expr {
+    Const(i64),
+    Add(String, String),
+    Mul(String, String),
+    Sub(String, String),
+    Div(String, String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Node {
+    Const(i64),
+    /// The unknown to solve for.
+    Var,
+    Op(Op, usize, usize),
+}
+
+/// How far a node's linear form has been worked out.
+#[derive(Clone, Copy, Debug)]
+enum Form {
+    InProgress,
+    Reduced(Linear),
+}
+
+/// Why a monkey's number could not be worked out exactly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    UnknownMonkey(String),
+    /// The monkey multiplies the unknown by itself or divides by it.
+    NotLinear(String),
+    DivisionByZero(String),
+    /// Both sides of the monkey's equality are equal for no value or for
+    /// every value of the unknown.
+    NoUniqueSolution(String),
+    NotAnInteger(Rational),
+    /// A number or coefficient the monkey works out does not fit.
+    Overflow(String),
+    /// The monkey waits for its own number.
+    Cycle(String),
+    Invalid(Vec<ValidationError>),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::UnknownMonkey(name) => write!(f, "No monkey is called {name}"),
+            SolveError::NotLinear(name) => {
+                write!(f, "Monkey {name} is not linear in the unknown")
+            }
+            SolveError::DivisionByZero(name) => write!(f, "Monkey {name} divides by zero"),
+            SolveError::NoUniqueSolution(name) => {
+                write!(f, "Monkey {name} has no unique solution")
+            }
+            SolveError::NotAnInteger(number) => write!(f, "{number} is not an integer"),
+            SolveError::Overflow(name) => {
+                write!(f, "Monkey {name} works out a number that does not fit")
+            }
+            SolveError::Cycle(name) => write!(f, "Monkey {name} waits for itself"),
+            SolveError::Invalid(errors) => {
+                let errors: Vec<_> = errors.iter().map(|err| err.to_string()).collect();
+                write!(f, "{}", errors.join("; "))
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl SolveError {
+    fn at(monkey: &str, err: LinearError) -> Self {
+        let monkey = monkey.to_string();
+        match err {
+            LinearError::NotLinear => SolveError::NotLinear(monkey),
+            LinearError::DivisionByZero => SolveError::DivisionByZero(monkey),
+            LinearError::NoUniqueSolution => SolveError::NoUniqueSolution(monkey),
+            LinearError::Overflow => SolveError::Overflow(monkey),
+        }
+    }
+}
+
+/// The monkeys' jobs as a graph of nodes in an arena, where every monkey
+/// appears once however many monkeys listen to it.
+#[derive(Clone, Debug)]
+pub struct Program {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+    nodes: Vec<Node>,
+}
+
+impl Program {
+    /// Compiles the jobs, with the monkey called `variable`, if any, yelling
+    /// an unknown number instead of doing its job. Cycles are only found when
+    /// reducing, without the path that [`validate`] reports.
+    pub fn compile(
+        monkeys: &HashMap<String, Expr>,
+        variable: Option<&str>,
+    ) -> Result<Self, SolveError> {
+        let mut names: Vec<String> = monkeys.keys().cloned().collect();
+        names.sort();
+        let index: HashMap<String, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(ix, name)| (name.clone(), ix))
+            .collect();
+        if let Some(variable) = variable.filter(|v| !index.contains_key(*v)) {
+            return Err(SolveError::UnknownMonkey(variable.to_string()));
+        }
+
+        let lookup = |name: &String| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| SolveError::UnknownMonkey(name.clone()))
+        };
+        let nodes = names
+            .iter()
+            .map(|name| {
+                if Some(name.as_str()) == variable {
+                    return Ok(Node::Var);
+                }
+                let (op, a, b) = match &monkeys[name] {
+                    Expr::Const(c) => return Ok(Node::Const(*c)),
+                    Expr::Add(a, b) => (Op::Add, a, b),
+                    Expr::Sub(a, b) => (Op::Sub, a, b),
+                    Expr::Mul(a, b) => (Op::Mul, a, b),
+                    Expr::Div(a, b) => (Op::Div, a, b),
+                };
+                Ok(Node::Op(op, lookup(a)?, lookup(b)?))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            names,
+            index,
+            nodes,
+        })
+    }
+
+    fn node(&self, name: &str) -> Result<usize, SolveError> {
+        self.index
+            .get(name)
+            .copied()
+            .ok_or_else(|| SolveError::UnknownMonkey(name.to_string()))
+    }
+
+    /// The number `monkey` yells, as a linear form of the unknown.
+    pub fn reduce(&self, monkey: &str) -> Result<Linear, SolveError> {
+        let mut forms = vec![None; self.nodes.len()];
+        self.reduce_node(self.node(monkey)?, &mut forms)
+    }
+
+    fn reduce_node(
+        &self,
+        node: usize,
+        forms: &mut Vec<Option<Form>>,
+    ) -> Result<Linear, SolveError> {
+        match forms[node] {
+            Some(Form::Reduced(form)) => return Ok(form),
+            Some(Form::InProgress) => return Err(SolveError::Cycle(self.names[node].clone())),
+            None => {}
+        }
+        forms[node] = Some(Form::InProgress);
+        let form = self.reduce_job(node, forms);
+        // a failed node is no longer in progress, so that a later walk over a
+        // shared `forms` finds its error again instead of a cycle
+        forms[node] = form.as_ref().ok().map(|&form| Form::Reduced(form));
+        form
+    }
+
+    fn reduce_job(&self, node: usize, forms: &mut Vec<Option<Form>>) -> Result<Linear, SolveError> {
+        Ok(match self.nodes[node] {
+            Node::Const(c) => Linear::constant(c.into()),
+            Node::Var => Linear::VARIABLE,
+            Node::Op(op, a, b) => {
+                let a = self.reduce_node(a, forms)?;
+                let b = self.reduce_node(b, forms)?;
+                match op {
+                    Op::Add => a.checked_add(b),
+                    Op::Sub => a.checked_sub(b),
+                    Op::Mul => a.checked_mul(b),
+                    Op::Div => a.checked_div(b),
+                }
+                .map_err(|err| SolveError::at(&self.names[node], err))?
+            }
+        })
+    }
+
+    /// The unknown for which both monkeys `monkey` listens to yell the same
+    /// number, whatever its job is.
+    pub fn solve_eq(&self, monkey: &str) -> Result<Rational, SolveError> {
+        let Node::Op(_, a, b) = self.nodes[self.node(monkey)?] else {
+            return Err(SolveError::NoUniqueSolution(monkey.to_string()));
+        };
+        let mut forms = vec![None; self.nodes.len()];
+        let a = self.reduce_node(a, &mut forms)?;
+        let b = self.reduce_node(b, &mut forms)?;
+        a.solve_eq(b).map_err(|err| SolveError::at(monkey, err))
+    }
+}
+
+fn to_integer(number: Rational) -> Result<i64, SolveError> {
+    number.to_integer().ok_or(SolveError::NotAnInteger(number))
+}
+
+pub fn parse(input: &str) -> Result<HashMap<String, Expr>, ParseError> {
+    let monkeys = parse_lines(input, |l| {
+        let (name, expr) = l
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(l, l, "Expected '<name>: <job>'"))?;
+        Ok((name.to_string(), parse_expr(l, expr)?))
+    })?;
+    Ok(monkeys.into_iter().collect())
+}
+
+fn parse_expr(line: &str, expr: &str) -> Result<Expr, ParseError> {
+    if let Ok(value) = expr.parse::<i64>() {
+        return Ok(Expr::Const(value));
+    }
+    let mut split = expr.split(' ');
+    let (Some(operand1), Some(operator), Some(operand2), None) =
+        (split.next(), split.next(), split.next(), split.next())
+    else {
+        return Err(ParseError::at(
+            line,
+            expr,
+            "Expected a number or '<name> <op> <name>'",
+        ));
+    };
+    let (operand1, operand2) = (operand1.to_string(), operand2.to_string());
+    match operator {
+        "+" => Ok(Expr::Add(operand1, operand2)),
+        "-" => Ok(Expr::Sub(operand1, operand2)),
+        "*" => Ok(Expr::Mul(operand1, operand2)),
+        "/" => Ok(Expr::Div(operand1, operand2)),
+        _ => Err(ParseError::at(line, operator, "Invalid operator")),
+    }
+}
+
+/// Compiles the jobs once [`validate`] finds nothing that blocks solving.
+fn compile_valid(
+    monkeys: &HashMap<String, Expr>,
+    variable: Option<&str>,
+) -> Result<Program, SolveError> {
+    if let Err(mut errors) = validate(monkeys, variable) {
+        errors.retain(ValidationError::blocks_solving);
+        if !errors.is_empty() {
+            return Err(SolveError::Invalid(errors));
+        }
+    }
+    Program::compile(monkeys, variable)
+}
+
+pub fn run_part1(monkeys: &HashMap<String, Expr>) -> Result<i64, SolveError> {
+    let program = compile_valid(monkeys, None)?;
+    to_integer(program.reduce(ROOT)?.b)
+}
+
+/// The root monkey checks that the two numbers it hears are equal.
+pub fn run_part2(monkeys: &HashMap<String, Expr>) -> Result<i64, SolveError> {
+    let program = compile_valid(monkeys, Some(HUMAN))?;
+    to_integer(program.solve_eq(ROOT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_solve() {
+        let x = Expr::Const(123);
+        let y = Expr::Const(2);
+        let sum = Expr::Add("x".to_string(), "y".to_string());
+        let context = HashMap::from([
+            ("x".to_string(), x),
+            ("y".to_string(), y),
+            ("sum".to_string(), sum),
+        ]);
+        let program = Program::compile(&context, Some("x")).unwrap();
+        let sum = program.reduce("sum").unwrap();
+        assert_eq!(sum.solve_eq(Linear::constant(5.into())), Ok(3.into()));
+    }
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST).unwrap()), Ok(152));
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST).unwrap()), Ok(301));
+    }
+
+    #[test]
+    fn test_exact_division() {
+        // 5 / 4 + 3 / 2 would be 1 + 1 with truncating division
+        let monkeys = parse(
+            "root: a + b\na: humn / four\nfour: 4\nb: three / two\nthree: 3\ntwo: 2\nhumn: 5",
+        )
+        .unwrap();
+        assert_eq!(
+            run_part1(&monkeys),
+            Err(SolveError::NotAnInteger(Rational::new(11, 4).unwrap()))
+        );
+        assert_eq!(run_part2(&monkeys), Ok(6));
+
+        // humn * 2 = 3 would give 1 with truncating division
+        let monkeys = parse("root: a + three\na: humn * two\ntwo: 2\nthree: 3\nhumn: 5").unwrap();
+        assert_eq!(
+            run_part2(&monkeys),
+            Err(SolveError::NotAnInteger(Rational::new(3, 2).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let monkeys = parse("root: a + b\na: humn * humn\nb: 4\nhumn: 2").unwrap();
+        assert_eq!(run_part1(&monkeys), Ok(8));
+        assert_eq!(
+            run_part2(&monkeys),
+            Err(SolveError::NotLinear("a".to_string()))
+        );
+
+        let monkeys = parse("root: a + b\na: humn / zero\nb: 4\nzero: 0\nhumn: 2").unwrap();
+        assert_eq!(
+            run_part2(&monkeys),
+            Err(SolveError::Invalid(vec![ValidationError::DivisionByZero {
+                monkey: "a".to_string(),
+                divisor: "zero".to_string()
+            }]))
+        );
+        let program = Program::compile(&monkeys, Some(HUMAN)).unwrap();
+        assert_eq!(
+            program.solve_eq(ROOT),
+            Err(SolveError::DivisionByZero("a".to_string()))
+        );
+
+        let monkeys = parse("root: a + b\na: humn - humn\nb: 4\nhumn: 2").unwrap();
+        assert_eq!(
+            run_part2(&monkeys),
+            Err(SolveError::NoUniqueSolution("root".to_string()))
+        );
+
+        let big = i64::MAX;
+        let monkeys = parse(&format!(
+            "root: c + c\nc: b * a\nb: a * a\na: {big}\nhumn: 1"
+        ))
+        .unwrap();
+        assert_eq!(
+            run_part1(&monkeys),
+            Err(SolveError::Overflow("c".to_string()))
+        );
+
+        // without validating first
+        let monkeys = parse("root: a + b\na: b * humn\nb: a - humn\nhumn: 1").unwrap();
+        let program = Program::compile(&monkeys, None).unwrap();
+        assert_eq!(
+            program.reduce(ROOT),
+            Err(SolveError::Cycle("a".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(&parse(INPUT_TEST).unwrap(), None), Ok(()));
+
+        let monkeys = parse(
+            "root: a + b\na: c * x\nb: d / z\nc: e - a\nd: 4\nz: d - d\ne: 1\nlonely: 3\nhumn: 5",
+        )
+        .unwrap();
+        assert_eq!(
+            validate(&monkeys, None),
+            Err(vec![
+                ValidationError::Undefined {
+                    monkey: "a".to_string(),
+                    name: "x".to_string()
+                },
+                ValidationError::Cycle(vec!["a".to_string(), "c".to_string(), "a".to_string()]),
+                ValidationError::Unreachable("humn".to_string()),
+                ValidationError::Unreachable("lonely".to_string()),
+            ])
+        );
+        let err = run_part1(&monkeys).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Monkeys wait for each other: a -> c -> a"));
+
+        let monkeys = parse("pppw: pppw + pppw").unwrap();
+        assert_eq!(
+            validate(&monkeys, None),
+            Err(vec![
+                ValidationError::NoRoot,
+                ValidationError::Cycle(vec!["pppw".to_string(), "pppw".to_string()]),
+            ])
+        );
+
+        // the divisor is only zero while humn yells 5
+        let monkeys =
+            parse("root: a + b\na: b / z\nb: 4\nz: humn - five\nfive: 5\nhumn: 5").unwrap();
+        let zero_divisor = ValidationError::DivisionByZero {
+            monkey: "a".to_string(),
+            divisor: "z".to_string(),
+        };
+        assert_eq!(validate(&monkeys, None), Err(vec![zero_divisor.clone()]));
+        assert_eq!(
+            run_part1(&monkeys),
+            Err(SolveError::Invalid(vec![zero_divisor]))
+        );
+        assert_eq!(validate(&monkeys, Some(HUMAN)), Ok(()));
+    }
+
+    #[test]
+    fn test_zero_divisor_after_failed_divisor() {
+        // d1 is not linear in humn, but d2 shares its operand and is still 0
+        let monkeys = parse(
+            "root: a + b\na: one / d1\nb: one / d2\nd1: s * s\nd2: s - s\ns: humn + one\none: 1\nhumn: 2",
+        )
+        .unwrap();
+        assert_eq!(
+            validate(&monkeys, Some(HUMAN)),
+            Err(vec![ValidationError::DivisionByZero {
+                monkey: "b".to_string(),
+                divisor: "d2".to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn test_unreachable_does_not_block() {
+        let monkeys = parse("root: humn + humn\nhumn: 1\nx: root * root").unwrap();
+        assert_eq!(
+            validate(&monkeys, Some(HUMAN)),
+            Err(vec![ValidationError::Unreachable("x".to_string())])
+        );
+        assert_eq!(run_part1(&monkeys), Ok(2));
+        assert_eq!(
+            run_part2(&monkeys),
+            Err(SolveError::NoUniqueSolution("root".to_string()))
+        );
+
+        let monkeys = parse("root: humn + one\none: 1\nhumn: 2\nx: root * root").unwrap();
+        assert_eq!(run_part2(&monkeys), Ok(1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("root: pppw + sjmn\npppw: cczh % lfqf").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 12, "%"));
+    }
+}
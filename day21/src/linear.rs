@@ -0,0 +1,240 @@
+use std::fmt::{self, Display, Formatter};
+
+/// An exact fraction in lowest terms, with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { num: 0, den: 1 };
+    pub const ONE: Self = Self { num: 1, den: 1 };
+
+    /// `None` if `den` is zero or the fraction does not fit.
+    pub fn new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let negative = (num < 0) != (den < 0);
+        let gcd = gcd(num.unsigned_abs(), den.unsigned_abs());
+        let num = i128::try_from(num.unsigned_abs() / gcd).ok()?;
+        let den = i128::try_from(den.unsigned_abs() / gcd).ok()?;
+        Some(Self {
+            num: if negative { -num } else { num },
+            den,
+        })
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    /// `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let num = (self.num.checked_mul(rhs.den)?).checked_add(rhs.num.checked_mul(self.den)?)?;
+        Self::new(num, self.den.checked_mul(rhs.den)?)
+    }
+
+    /// `None` on overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    /// `None` on overflow.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    /// `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::new(
+            self.num.checked_mul(rhs.num)?,
+            self.den.checked_mul(rhs.den)?,
+        )
+    }
+
+    /// `None` when dividing by zero or on overflow.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::new(
+            self.num.checked_mul(rhs.den)?,
+            self.den.checked_mul(rhs.num)?,
+        )
+    }
+
+    /// The value as an integer, if it is one and fits.
+    pub fn to_integer(self) -> Option<i64> {
+        if self.den == 1 {
+            self.num.try_into().ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self {
+            num: n as i128,
+            den: 1,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Why two linear forms could not be combined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinearError {
+    /// The result would not be linear in the unknown.
+    NotLinear,
+    DivisionByZero,
+    /// Both sides are equal for no value or for every value of the unknown.
+    NoUniqueSolution,
+    /// A coefficient does not fit in a fraction of `i128`s.
+    Overflow,
+}
+
+/// `a * x + b` for some unknown `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Linear {
+    pub a: Rational,
+    pub b: Rational,
+}
+
+impl Linear {
+    pub const VARIABLE: Self = Self {
+        a: Rational::ONE,
+        b: Rational::ZERO,
+    };
+
+    pub fn constant(b: Rational) -> Self {
+        Self {
+            a: Rational::ZERO,
+            b,
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.a.is_zero()
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Result<Self, LinearError> {
+        Ok(Self {
+            a: self.a.checked_add(rhs.a).ok_or(LinearError::Overflow)?,
+            b: self.b.checked_add(rhs.b).ok_or(LinearError::Overflow)?,
+        })
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, LinearError> {
+        Ok(Self {
+            a: self.a.checked_sub(rhs.a).ok_or(LinearError::Overflow)?,
+            b: self.b.checked_sub(rhs.b).ok_or(LinearError::Overflow)?,
+        })
+    }
+
+    /// Only linear if one side is constant.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, LinearError> {
+        if !self.is_constant() && !rhs.is_constant() {
+            return Err(LinearError::NotLinear);
+        }
+        let a = (self.a.checked_mul(rhs.b))
+            .zip(self.b.checked_mul(rhs.a))
+            .and_then(|(a1, a2)| a1.checked_add(a2));
+        Ok(Self {
+            a: a.ok_or(LinearError::Overflow)?,
+            b: self.b.checked_mul(rhs.b).ok_or(LinearError::Overflow)?,
+        })
+    }
+
+    /// Only linear if `rhs` is constant.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, LinearError> {
+        if !rhs.is_constant() {
+            return Err(LinearError::NotLinear);
+        }
+        if rhs.b.is_zero() {
+            return Err(LinearError::DivisionByZero);
+        }
+        Ok(Self {
+            a: self.a.checked_div(rhs.b).ok_or(LinearError::Overflow)?,
+            b: self.b.checked_div(rhs.b).ok_or(LinearError::Overflow)?,
+        })
+    }
+
+    /// The `x` for which both sides are equal, if there is exactly one.
+    pub fn solve_eq(self, rhs: Self) -> Result<Rational, LinearError> {
+        let a = self.a.checked_sub(rhs.a).ok_or(LinearError::Overflow)?;
+        if a.is_zero() {
+            return Err(LinearError::NoUniqueSolution);
+        }
+        let b = rhs.b.checked_sub(self.b).ok_or(LinearError::Overflow)?;
+        b.checked_div(a).ok_or(LinearError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, -4).unwrap();
+        assert_eq!(Some(half), Rational::new(-1, 2));
+        assert_eq!(half.to_string(), "-1/2");
+        assert_eq!(half.checked_add(half).unwrap().to_integer(), Some(-1));
+        assert_eq!(half.checked_mul(3.into()), Rational::new(-3, 2));
+        assert_eq!(half.checked_div(Rational::ZERO), None);
+        assert_eq!(Rational::new(1, 0), None);
+
+        let big = Rational::from(i64::MAX);
+        let square = big.checked_mul(big).unwrap();
+        assert_eq!(square.checked_mul(big), None);
+        assert_eq!(
+            square.checked_add(square).unwrap().checked_add(square),
+            None
+        );
+    }
+
+    #[test]
+    fn test_linear() {
+        // (4 - x) / 2 * 3 = 3
+        let four = Linear::constant(4.into());
+        let lhs = (four.checked_sub(Linear::VARIABLE))
+            .and_then(|lhs| lhs.checked_div(Linear::constant(2.into())))
+            .and_then(|lhs| lhs.checked_mul(Linear::constant(3.into())))
+            .unwrap();
+        assert_eq!(lhs.solve_eq(Linear::constant(3.into())), Ok(2.into()));
+        assert_eq!(
+            Linear::VARIABLE.checked_mul(Linear::VARIABLE),
+            Err(LinearError::NotLinear)
+        );
+        assert_eq!(
+            four.checked_div(Linear::VARIABLE),
+            Err(LinearError::NotLinear)
+        );
+        assert_eq!(
+            four.checked_div(Linear::constant(Rational::ZERO)),
+            Err(LinearError::DivisionByZero)
+        );
+        assert_eq!(four.solve_eq(four), Err(LinearError::NoUniqueSolution));
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::{Expr, Node, Op, Program, ROOT};
+
+/// A problem with the monkeys' jobs. All but [`ValidationError::Unreachable`]
+/// keep the root's number from being worked out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    NoRoot,
+    Undefined {
+        monkey: String,
+        name: String,
+    },
+    /// Monkeys that wait for each other, starting and ending with the same
+    /// monkey.
+    Cycle(Vec<String>),
+    /// A monkey whose number root never hears.
+    Unreachable(String),
+    DivisionByZero {
+        monkey: String,
+        divisor: String,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::NoRoot => write!(f, "No monkey is called {ROOT}"),
+            ValidationError::Undefined { monkey, name } => {
+                write!(f, "Monkey {monkey} listens to {name}, who does not exist")
+            }
+            ValidationError::Cycle(path) => {
+                write!(f, "Monkeys wait for each other: {}", path.join(" -> "))
+            }
+            ValidationError::Unreachable(monkey) => {
+                write!(f, "Monkey {monkey} is never heard by {ROOT}")
+            }
+            ValidationError::DivisionByZero { monkey, divisor } => {
+                write!(f, "Monkey {monkey} divides by {divisor}, who yells 0")
+            }
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+impl ValidationError {
+    /// Whether the problem keeps the root's number from being worked out.
+    pub fn blocks_solving(&self) -> bool {
+        !matches!(self, ValidationError::Unreachable(_))
+    }
+}
+
+impl Expr {
+    fn operands(&self) -> Vec<&str> {
+        match self {
+            Expr::Const(_) => Vec::new(),
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => {
+                vec![a, b]
+            }
+        }
+    }
+}
+
+/// Checks that every monkey's number, root's in particular, can be worked
+/// out with the monkey called `variable`, if any, yelling an unknown number,
+/// and reports every problem found.
+pub fn validate(
+    monkeys: &HashMap<String, Expr>,
+    variable: Option<&str>,
+) -> Result<(), Vec<ValidationError>> {
+    let mut names: Vec<&str> = monkeys.keys().map(String::as_str).collect();
+    names.sort_unstable();
+
+    let mut errors = Vec::new();
+    if !monkeys.contains_key(ROOT) {
+        errors.push(ValidationError::NoRoot);
+    }
+    for &monkey in &names {
+        for name in monkeys[monkey].operands() {
+            if !monkeys.contains_key(name) {
+                errors.push(ValidationError::Undefined {
+                    monkey: monkey.to_string(),
+                    name: name.to_string(),
+                });
+            }
+        }
+    }
+
+    let mut walk = Walk {
+        monkeys,
+        visits: HashMap::new(),
+        path: Vec::new(),
+        errors: &mut errors,
+    };
+    for &monkey in &names {
+        walk.visit(monkey);
+    }
+
+    // the jobs only reduce once every name is defined and nobody waits in a
+    // cycle
+    if errors.iter().all(|err| *err == ValidationError::NoRoot) {
+        if let Ok(program) = Program::compile(monkeys, variable) {
+            errors.extend(program.zero_divisors());
+        }
+    }
+
+    if monkeys.contains_key(ROOT) {
+        let mut heard = HashSet::from([ROOT]);
+        let mut todo = vec![ROOT];
+        while let Some(monkey) = todo.pop() {
+            for name in monkeys[monkey].operands() {
+                if monkeys.contains_key(name) && heard.insert(name) {
+                    todo.push(name);
+                }
+            }
+        }
+        errors.extend(
+            names
+                .iter()
+                .filter(|monkey| !heard.contains(*monkey))
+                .map(|monkey| ValidationError::Unreachable(monkey.to_string())),
+        );
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+enum Visit {
+    InProgress,
+    Done,
+}
+
+/// Depth-first walk over who listens to whom, finding cycles on the way.
+struct Walk<'a, 'e> {
+    monkeys: &'a HashMap<String, Expr>,
+    visits: HashMap<&'a str, Visit>,
+    /// Monkeys in progress, each waiting for the next.
+    path: Vec<&'a str>,
+    errors: &'e mut Vec<ValidationError>,
+}
+
+impl<'a> Walk<'a, '_> {
+    fn visit(&mut self, monkey: &'a str) {
+        match self.visits.get(monkey) {
+            Some(Visit::Done) => return,
+            Some(Visit::InProgress) => {
+                let start = self.path.iter().position(|&m| m == monkey).unwrap();
+                let mut cycle: Vec<_> = self.path[start..].iter().map(|m| m.to_string()).collect();
+                cycle.push(monkey.to_string());
+                // a monkey that listens to itself twice closes the same cycle
+                // twice
+                let cycle = ValidationError::Cycle(cycle);
+                if !self.errors.contains(&cycle) {
+                    self.errors.push(cycle);
+                }
+                return;
+            }
+            None => {}
+        }
+        // undefined monkeys are reported by name
+        let Some((name, expr)) = self.monkeys.get_key_value(monkey) else {
+            return;
+        };
+        self.visits.insert(name, Visit::InProgress);
+        self.path.push(name);
+        for operand in expr.operands() {
+            self.visit(operand);
+        }
+        self.path.pop();
+        self.visits.insert(name, Visit::Done);
+    }
+}
+
+impl Program {
+    /// Monkeys that divide by a monkey yelling 0, whatever the unknown is.
+    fn zero_divisors(&self) -> Vec<ValidationError> {
+        let mut forms = vec![None; self.nodes.len()];
+        let mut errors = Vec::new();
+        for (name, &node) in self.names.iter().zip(&self.nodes) {
+            let Node::Op(Op::Div, _, divisor) = node else {
+                continue;
+            };
+            // other problems are reported when solving
+            let form = self.reduce_node(divisor, &mut forms);
+            if form.is_ok_and(|form| form.is_constant() && form.b.is_zero()) {
+                errors.push(ValidationError::DivisionByZero {
+                    monkey: name.clone(),
+                    divisor: self.names[divisor].clone(),
+                });
+            }
+        }
+        errors
+    }
+}
@@ -0,0 +1,443 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Formatter},
+};
+
+use common::{search, ParseError, PartResult, Solution};
+use grid::{Grid, Pos, NEIGHBOURS4};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Move {
+    Forward(u32),
+    Left,
+    Right,
+}
+
+/// Facings are indices into [`NEIGHBOURS4`]: right, down, left, up.
+pub type Facing = usize;
+
+#[derive(Debug)]
+pub struct MonkeyMap {
+    board: Grid<Tile>,
+    path: Vec<Move>,
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = MonkeyMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, map: &Self::Input) -> PartResult {
+        Ok(run_part1(map).into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> PartResult {
+        Ok(run_part2(map)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<MonkeyMap, ParseError> {
+    // with CRLF line endings the blank line would be "\r\n\r\n"
+    let input = &input.replace("\r\n", "\n");
+    let (board, path) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at(
+            input,
+            input,
+            "Expected a board and a path separated by a blank line",
+        )
+    })?;
+
+    let lines: Vec<_> = board.lines().collect();
+    let Some(width) = lines.iter().map(|line| line.chars().count()).max() else {
+        return Err(ParseError::at(input, board, "Empty board"));
+    };
+    let mut grid = Grid::new(width, lines.len(), Tile::Void);
+    for (y, line) in lines.iter().enumerate() {
+        for (x, (ix, c)) in line.char_indices().enumerate() {
+            grid[(x as i64, y as i64)] = match c {
+                ' ' => Tile::Void,
+                '.' => Tile::Open,
+                '#' => Tile::Wall,
+                _ => {
+                    let span = &line[ix..ix + c.len_utf8()];
+                    return Err(ParseError::at(input, span, "Unexpected character"));
+                }
+            };
+        }
+    }
+    if !grid.iter().any(|(_, &tile)| tile == Tile::Open) {
+        return Err(ParseError::at(input, board, "No open tile"));
+    }
+
+    Ok(MonkeyMap {
+        board: grid,
+        path: parse_path(input, path.trim())?,
+    })
+}
+
+fn parse_path(input: &str, path: &str) -> Result<Vec<Move>, ParseError> {
+    let mut moves = Vec::new();
+    let mut rest = path;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (token, tail) = if digits > 0 {
+            rest.split_at(digits)
+        } else {
+            rest.split_at(rest.chars().next().unwrap().len_utf8())
+        };
+        moves.push(match token {
+            "L" => Move::Left,
+            "R" => Move::Right,
+            _ => Move::Forward(
+                token
+                    .parse()
+                    .map_err(|_| ParseError::at(input, token, "Invalid move"))?,
+            ),
+        });
+        rest = tail;
+    }
+    if moves.is_empty() {
+        return Err(ParseError::at(input, path, "Empty path"));
+    }
+    Ok(moves)
+}
+
+/// Follows the path from the leftmost open tile of the top row. `wrap` gives
+/// the position and facing after stepping off the board from the given ones.
+pub fn walk(map: &MonkeyMap, mut wrap: impl FnMut(Pos, Facing) -> (Pos, Facing)) -> (Pos, Facing) {
+    let mut pos = map.board.position(|&tile| tile == Tile::Open).unwrap();
+    let mut facing = 0;
+    for &step in &map.path {
+        match step {
+            Move::Left => facing = (facing + 3) % 4,
+            Move::Right => facing = (facing + 1) % 4,
+            Move::Forward(n) => {
+                for _ in 0..n {
+                    let (dx, dy) = NEIGHBOURS4[facing];
+                    let ahead = (pos.0 + dx, pos.1 + dy);
+                    let (next, next_facing) = match map.board.get(ahead) {
+                        Some(Tile::Open | Tile::Wall) => (ahead, facing),
+                        _ => wrap(pos, facing),
+                    };
+                    if map.board[next] == Tile::Wall {
+                        break;
+                    }
+                    (pos, facing) = (next, next_facing);
+                }
+            }
+        }
+    }
+    (pos, facing)
+}
+
+pub fn password((pos, facing): (Pos, Facing)) -> i64 {
+    1000 * (pos.1 + 1) + 4 * (pos.0 + 1) + facing as i64
+}
+
+/// Wraps around to the other side of the board, in the same row or column.
+pub fn wrap_flat(board: &Grid<Tile>, pos: Pos, facing: Facing) -> (Pos, Facing) {
+    let (dx, dy) = NEIGHBOURS4[facing];
+    let mut pos = pos;
+    while board
+        .get((pos.0 - dx, pos.1 - dy))
+        .is_some_and(|&tile| tile != Tile::Void)
+    {
+        pos = (pos.0 - dx, pos.1 - dy);
+    }
+    (pos, facing)
+}
+
+type Vec3 = [i8; 3];
+
+fn neg(v: Vec3) -> Vec3 {
+    v.map(|c| -c)
+}
+
+/// How a face of the net lies on the cube: the directions of its right and
+/// down edges and its outward normal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Frame {
+    right: Vec3,
+    down: Vec3,
+    normal: Vec3,
+}
+
+impl Frame {
+    /// The direction of the side of the face in the direction of `facing`.
+    fn side(&self, facing: Facing) -> Vec3 {
+        match facing {
+            0 => self.right,
+            1 => self.down,
+            2 => neg(self.right),
+            _ => neg(self.down),
+        }
+    }
+
+    /// The frame of the face next to this one in the direction of `facing`,
+    /// once the net is folded along their shared edge.
+    fn roll(&self, facing: Facing) -> Frame {
+        let normal = self.side(facing);
+        match facing {
+            0 => Frame {
+                right: neg(self.normal),
+                normal,
+                ..*self
+            },
+            1 => Frame {
+                down: neg(self.normal),
+                normal,
+                ..*self
+            },
+            2 => Frame {
+                right: self.normal,
+                normal,
+                ..*self
+            },
+            _ => Frame {
+                down: self.normal,
+                normal,
+                ..*self
+            },
+        }
+    }
+}
+
+/// The board is not the net of a cube, so it cannot be folded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotACubeError;
+
+impl fmt::Display for NotACubeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "The board is not the net of a cube")
+    }
+}
+
+impl Error for NotACubeError {}
+
+/// The board folded into a cube. The layout of the net is found from the
+/// board itself, so any of the eleven nets works.
+pub struct Cube {
+    size: i64,
+    /// Faces by their position in the net, in units of `size`.
+    faces: HashMap<Pos, Frame>,
+}
+
+impl Cube {
+    pub fn fold(board: &Grid<Tile>) -> Option<Self> {
+        let area = board.iter().filter(|(_, &tile)| tile != Tile::Void).count() as i64;
+        let size = (1..).find(|size| 6 * size * size >= area)?;
+        if 6 * size * size != area {
+            return None;
+        }
+        let is_face = |(fx, fy): Pos| {
+            board
+                .get((fx * size, fy * size))
+                .is_some_and(|&tile| tile != Tile::Void)
+        };
+
+        let first = board.position(|&tile| tile != Tile::Void)?;
+        let first = (first.0 / size, first.1 / size);
+        let unfolded = Frame {
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+            normal: [0, 0, 1],
+        };
+        // faces of a net are never adjacent in a loop, so each is reached once
+        let search = search::bfs(
+            [(first, unfolded)],
+            |&(face, frame)| {
+                NEIGHBOURS4
+                    .iter()
+                    .enumerate()
+                    .map(move |(facing, (dx, dy))| ((face.0 + dx, face.1 + dy), frame.roll(facing)))
+                    .filter(|&(next, _)| is_face(next))
+            },
+            |_| false,
+        );
+        let faces: HashMap<Pos, Frame> = search.visited().copied().collect();
+
+        let mut normals: Vec<_> = faces.values().map(|frame| frame.normal).collect();
+        normals.sort();
+        normals.dedup();
+        (faces.len() == 6 && normals.len() == 6).then_some(Self { size, faces })
+    }
+
+    /// Moves over the edge of the face that `pos` is on, onto the face that
+    /// shares that edge on the cube.
+    pub fn wrap(&self, pos: Pos, facing: Facing) -> (Pos, Facing) {
+        let from = self.faces[&(pos.0 / self.size, pos.1 / self.size)];
+        let (x, y) = (pos.0 % self.size, pos.1 % self.size);
+        let (tangent, t) = if facing.is_multiple_of(2) {
+            (from.down, y)
+        } else {
+            (from.right, x)
+        };
+
+        let target = from.side(facing);
+        let (&face, to) = self
+            .faces
+            .iter()
+            .find(|(_, frame)| frame.normal == target)
+            .unwrap();
+        let side = (0..4).find(|&side| to.side(side) == from.normal).unwrap();
+        let along = if side.is_multiple_of(2) {
+            to.down
+        } else {
+            to.right
+        };
+        let t = if along == tangent {
+            t
+        } else {
+            self.size - 1 - t
+        };
+
+        let last = self.size - 1;
+        let (x, y) = match side {
+            0 => (last, t),
+            1 => (t, last),
+            2 => (0, t),
+            _ => (t, 0),
+        };
+        (
+            (face.0 * self.size + x, face.1 * self.size + y),
+            (side + 2) % 4,
+        )
+    }
+}
+
+pub fn run_part1(map: &MonkeyMap) -> i64 {
+    password(walk(map, |pos, facing| wrap_flat(&map.board, pos, facing)))
+}
+
+pub fn run_part2(map: &MonkeyMap) -> Result<i64, NotACubeError> {
+    let cube = Cube::fold(&map.board).ok_or(NotACubeError)?;
+    Ok(password(walk(map, |pos, facing| cube.wrap(pos, facing))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_parse() {
+        let map = parse(INPUT_TEST).unwrap();
+        assert_eq!((map.board.width(), map.board.height()), (16, 12));
+        assert_eq!(map.board[(11, 0)], Tile::Wall);
+        assert_eq!(map.board[(12, 0)], Tile::Void);
+        assert_eq!(map.path.len(), 13);
+        assert_eq!(
+            &map.path[..3],
+            &[Move::Forward(10), Move::Right, Move::Forward(5)]
+        );
+    }
+
+    #[test]
+    fn test_parse_crlf() {
+        let map = parse(&INPUT_TEST.replace('\n', "\r\n")).unwrap();
+        assert_eq!((map.board.width(), map.board.height()), (16, 12));
+        assert_eq!(map.path.len(), 13);
+    }
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST).unwrap()), 6032);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST).unwrap()), Ok(5031));
+    }
+
+    #[test]
+    fn test_wrap_cube() {
+        let map = parse(INPUT_TEST).unwrap();
+        let cube = Cube::fold(&map.board).unwrap();
+        // the examples from the puzzle description
+        assert_eq!(cube.wrap((11, 5), 0), ((14, 8), 1));
+        assert_eq!(cube.wrap((10, 11), 1), ((1, 7), 3));
+        assert_eq!(cube.wrap((6, 4), 3), ((8, 2), 0));
+    }
+
+    /// Walking straight ahead on an open cube comes back to the start after
+    /// going around it once.
+    fn assert_round_trips(net: &str, size: usize) {
+        let board: String = net
+            .lines()
+            .flat_map(|row| {
+                let row: String = row
+                    .chars()
+                    .flat_map(|c| std::iter::repeat_n(if c == '#' { '.' } else { ' ' }, size))
+                    .collect();
+                std::iter::repeat_n(row, size)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let board = parse(&format!("{board}\n\n1")).unwrap().board;
+        let cube = Cube::fold(&board).unwrap();
+        for (start, _) in board.iter().filter(|(_, &tile)| tile == Tile::Open) {
+            for facing in 0..4 {
+                let (mut pos, mut f) = (start, facing);
+                for _ in 0..4 * size {
+                    let (dx, dy) = NEIGHBOURS4[f];
+                    let ahead = (pos.0 + dx, pos.1 + dy);
+                    (pos, f) = match board.get(ahead) {
+                        Some(Tile::Open) => (ahead, f),
+                        _ => cube.wrap(pos, f),
+                    };
+                }
+                assert_eq!((pos, f), (start, facing), "net:\n{net}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_fold_nets() {
+        // the example's layout
+        assert_round_trips("  #\n###\n  ##", 4);
+        // the layout of the real inputs, also at their size
+        assert_round_trips(" ##\n #\n##\n#", 3);
+        assert_round_trips(" ##\n #\n##\n#", 50);
+        assert_round_trips(" #\n###\n #\n #", 2);
+    }
+
+    #[test]
+    fn test_fold_not_a_cube() {
+        let map = parse("....\n....\n\n1").unwrap();
+        assert!(Cube::fold(&map.board).is_none());
+        let map = parse("......\n\n1").unwrap();
+        assert!(Cube::fold(&map.board).is_none());
+        // six faces, but two of them fold onto the same side
+        let map = parse(".\n...\n.\n.\n\n1").unwrap();
+        assert!(Cube::fold(&map.board).is_none());
+        // part 1 can still walk a board that part 2 cannot fold
+        let map = parse("...\n...\n\n1").unwrap();
+        assert_eq!(run_part1(&map), 1008);
+        assert_eq!(run_part2(&map), Err(NotACubeError));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..\n.x\n\n1R2").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        let err = parse("..\n..\n\n1R2X").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 4, "X"));
+        let err = parse("\n\n1").unwrap_err();
+        assert_eq!(err.message, "Empty board");
+    }
+}
@@ -1,12 +1,9 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{BufRead, BufReader, Lines},
-    str::FromStr,
-};
+use std::{collections::HashSet, str::FromStr};
+
+use common::{parse_lines, ParseError, PartResult, Solution};
 use itertools::Itertools;
 
-struct Rucksack {
+pub struct Rucksack {
     compartment1: HashSet<char>,
     compartment2: HashSet<char>,
 }
@@ -34,8 +31,14 @@ impl Rucksack {
 }
 
 impl FromStr for Rucksack {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((ix, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::at(s, &s[ix..ix + c.len_utf8()], "Invalid item"));
+        }
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseError::at(s, s, "Compartments of different sizes"));
+        }
         let mid = s.len() / 2;
         let c1 = &s[..mid];
         let c2 = &s[mid..];
@@ -60,56 +63,60 @@ fn priority(c: char) -> u32 {
     }
 }
 
-fn main() {
-    let result = run("input.txt");
-    let result2 = run_part2("input.txt");
-    println!("Result: {result} - Result part 2: {result2}");
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> PartResult {
+        Ok(run_part1(rucksacks).into())
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> PartResult {
+        Ok(run_part2(rucksacks).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse_lines(input, Rucksack::from_str)
 }
 
-fn run(filename: &str) -> u32 {
-    read_file(filename)
-        .filter_map(|res| Some(res.ok()?))
-        .map(|s| {
-            priority(
-                *Rucksack::from_str(&s)
-                    .expect("Invalid rucksack")
-                    .duplicate_item(),
-            )
-        })
+pub fn run_part1(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| priority(*rucksack.duplicate_item()))
         .sum()
 }
 
-fn run_part2(filename: &str) -> u32 {
-    read_file(filename)
-        .filter_map(|res| Some(res.ok()?))
+pub fn run_part2(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks
+        .iter()
         .tuples()
-        .map(|(l1, l2, l3)| {
-            let r1 = Rucksack::from_str(&l1).expect("Invalid rucksack").all_items();
-            let r2 = Rucksack::from_str(&l2).expect("Invalid rucksack").all_items();
-            let r3 = Rucksack::from_str(&l3).expect("Invalid rucksack").all_items();
+        .map(|(r1, r2, r3)| {
+            let r1 = r1.all_items();
+            let r2 = r2.all_items();
+            let r3 = r3.all_items();
             let intersection1and2 = r1
                 .intersection(&r2)
                 .cloned()
                 .collect::<HashSet<_>>();
             let mut intersection = intersection1and2.intersection(&r3);
             let common_item = intersection.next().unwrap();
-            let prio = priority(*common_item);
-            println!("Common item: {}, priority: {}", common_item, prio);
-            prio
+            priority(*common_item)
         })
         .sum()
 }
 
-fn read_file(filename: &str) -> Lines<BufReader<File>> {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-    reader.lines()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
     #[test]
     fn test_priorty() {
         assert_eq!(priority('a'), 1);
@@ -120,11 +127,11 @@ mod tests {
 
     #[test]
     fn test_input() {
-        assert_eq!(run("input_test.txt"), 157);
+        assert_eq!(run_part1(&parse(INPUT_TEST).unwrap()), 157);
     }
 
     #[test]
     fn test_input_part2() {
-        assert_eq!(run_part2("input_test.txt"), 70);
+        assert_eq!(run_part2(&parse(INPUT_TEST).unwrap()), 70);
     }
 }
@@ -0,0 +1,93 @@
+use common::{parse_lines, ParseError, PartResult, Solution};
+
+pub type Assignment = (u32, u32);
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Assignment, Assignment)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> PartResult {
+        Ok(run_part1(pairs).into())
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> PartResult {
+        Ok(run_part2(pairs).into())
+    }
+}
+
+pub fn run_part1(pairs: &[(Assignment, Assignment)]) -> usize {
+    run(pairs, is_contained_in_or_contains)
+}
+
+pub fn run_part2(pairs: &[(Assignment, Assignment)]) -> usize {
+    run(pairs, overlaps)
+}
+
+fn run(
+    pairs: &[(Assignment, Assignment)],
+    check: impl Fn(Assignment, Assignment) -> bool,
+) -> usize {
+    pairs.iter().filter(|(a1, a2)| check(*a1, *a2)).count()
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+    parse_lines(input, parse_line)
+}
+
+fn parse_line(s: &str) -> Result<(Assignment, Assignment), ParseError> {
+    let (a, b) = s
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(s, s, "Expected two assignments"))?;
+    Ok((parse_assignment(s, a)?, parse_assignment(s, b)?))
+}
+
+fn parse_assignment(line: &str, s: &str) -> Result<Assignment, ParseError> {
+    let (start, end) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(line, s, "Expected a range"))?;
+    let section = |n: &str| {
+        n.parse::<u32>()
+            .map_err(|_| ParseError::at(line, n, "Invalid section"))
+    };
+    Ok((section(start)?, section(end)?))
+}
+
+fn is_contained_in_or_contains(a: Assignment, b: Assignment) -> bool {
+    is_contained_in(a, b) || is_contained_in(b, a)
+}
+
+fn is_contained_in(a: Assignment, b: Assignment) -> bool {
+    a.0 >= b.0 && a.1 <= b.1
+}
+
+fn overlaps(a: Assignment, b: Assignment) -> bool {
+    (a.0..=a.1).any(|i| (b.0..=b.1).contains(&i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("2-4,6-8\n2-3,4x5").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "4x5"));
+    }
+}
@@ -0,0 +1,209 @@
+use common::{ParseError, PartResult, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Instruction {
+    num_crates: usize,
+    from: usize,
+    to: usize,
+}
+
+pub type Stack = Vec<char>;
+pub type Stacks = Vec<Stack>;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Stacks, Vec<Instruction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, (stacks, instructions): &Self::Input) -> PartResult {
+        Ok(run_part1(stacks, instructions)
+            .ok_or("A stack ends up empty")?
+            .into())
+    }
+
+    fn part2(&self, (stacks, instructions): &Self::Input) -> PartResult {
+        Ok(run_part2(stacks, instructions)
+            .ok_or("A stack ends up empty")?
+            .into())
+    }
+}
+
+/// The crates on top of the stacks, `None` if a stack ends up empty.
+pub fn run_part1(stacks: &Stacks, instructions: &[Instruction]) -> Option<String> {
+    run(stacks, instructions, execute_instruction)
+}
+
+/// The crates on top of the stacks, `None` if a stack ends up empty.
+pub fn run_part2(stacks: &Stacks, instructions: &[Instruction]) -> Option<String> {
+    run(stacks, instructions, execute_instruction_part2)
+}
+
+pub fn parse(input: &str) -> Result<(Stacks, Vec<Instruction>), ParseError> {
+    let mut lines = input.lines().enumerate();
+    let initial_stacks_t: Stacks = lines
+        .by_ref()
+        .take_while(|(_, s)| !s.is_empty())
+        .map(|(_, s)| {
+            s.chars()
+                .skip(1)
+                .enumerate()
+                .filter_map(|(i, c)| if i % 4 == 0 { Some(c) } else { None })
+                .collect::<Stack>()
+        })
+        .collect();
+    if initial_stacks_t.is_empty() {
+        return Err(ParseError::at(input, input, "Missing stacks"));
+    }
+
+    let stacks = transpose_stacks(initial_stacks_t);
+
+    // both cranes move the same number of crates, so the heights tell which
+    // moves take more crates than there are
+    let mut heights: Vec<_> = stacks.iter().map(Vec::len).collect();
+    let instructions = lines
+        .map(|(ix, line)| parse_instruction(line, &mut heights).map_err(|err| err.shift_lines(ix)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((stacks, instructions))
+}
+
+fn run(
+    stacks: &Stacks,
+    instructions: &[Instruction],
+    f: impl Fn(Instruction, &mut Stacks),
+) -> Option<String> {
+    let mut stacks = stacks.clone();
+
+    execute(instructions, &mut stacks, f);
+
+    stacks.iter().map(|s| s.last().copied()).collect()
+}
+
+fn execute(
+    instructions: &[Instruction],
+    stacks: &mut Stacks,
+    f: impl Fn(Instruction, &mut Stacks),
+) {
+    for &instruction in instructions {
+        f(instruction, stacks);
+    }
+}
+
+fn execute_instruction(instruction: Instruction, stacks: &mut Stacks) {
+    for _ in 0..instruction.num_crates {
+        let item = stacks[instruction.from].pop().unwrap();
+        stacks[instruction.to].push(item);
+    }
+}
+
+fn execute_instruction_part2(instruction: Instruction, stacks: &mut Stacks) {
+    let from_stack = &mut stacks[instruction.from];
+    let items_to_move = from_stack.split_off(from_stack.len() - instruction.num_crates);
+    stacks[instruction.to].extend(items_to_move);
+}
+
+/// Parses a move and applies it to `heights`, the number of crates on each
+/// stack.
+fn parse_instruction(instruction: &str, heights: &mut [usize]) -> Result<Instruction, ParseError> {
+    let num_stacks = heights.len();
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+    }
+    let captures = RE
+        .captures(instruction)
+        .ok_or_else(|| ParseError::at(instruction, instruction, "Invalid instruction"))?;
+    let number = |i: usize| {
+        let s = captures.get(i).unwrap().as_str();
+        s.parse::<usize>()
+            .map_err(|_| ParseError::at(instruction, s, "Invalid number"))
+    };
+    let stack = |i: usize| {
+        let s = captures.get(i).unwrap().as_str();
+        match number(i)? {
+            n @ 1.. if n <= num_stacks => Ok(n - 1),
+            _ => Err(ParseError::at(instruction, s, "No such stack")),
+        }
+    };
+    let parsed = Instruction {
+        num_crates: number(1)?,
+        from: stack(2)?,
+        to: stack(3)?,
+    };
+    if heights[parsed.from] < parsed.num_crates {
+        let span = captures.get(1).unwrap().as_str();
+        return Err(ParseError::at(
+            instruction,
+            span,
+            "Not enough crates to move",
+        ));
+    }
+    heights[parsed.from] -= parsed.num_crates;
+    heights[parsed.to] += parsed.num_crates;
+    Ok(parsed)
+}
+
+fn transpose_stacks(stacks_t: Stacks) -> Stacks {
+    let num_stacks = stacks_t[0].len();
+    (0..num_stacks)
+        .map(|i| {
+            stacks_t
+                .iter()
+                .rev()
+                .skip(1)
+                .filter_map(|inner| {
+                    let c = *inner.get(i)?;
+                    if c != ' ' {
+                        Some(c)
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Stack>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        let (stacks, instructions) = parse(INPUT_TEST).unwrap();
+        assert_eq!(run_part1(&stacks, &instructions), Some("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_input_part2() {
+        let (stacks, instructions) = parse(INPUT_TEST).unwrap();
+        assert_eq!(run_part2(&stacks, &instructions), Some("MCD".to_string()));
+    }
+
+    #[test]
+    fn test_empty_stack() {
+        let input = INPUT_TEST.replace("move 1 from 1 to 2", "move 2 from 1 to 2");
+        let (stacks, instructions) = parse(&input).unwrap();
+        assert_eq!(run_part1(&stacks, &instructions), None);
+        assert_eq!(run_part2(&stacks, &instructions), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT_TEST.replace("from 1 to 3", "from 1 to 4");
+        let err = parse(&input).err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (7, 18, "4"));
+
+        let input = INPUT_TEST.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+        let err = parse(&input).err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (7, 6, "4"));
+        assert_eq!(err.message, "Not enough crates to move");
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::{hash_map::RandomState, HashSet};
+
+use common::{ParseError, PartResult, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, line: &Self::Input) -> PartResult {
+        Ok(run_part1(line).ok_or("No start-of-packet marker")?.into())
+    }
+
+    fn part2(&self, line: &Self::Input) -> PartResult {
+        Ok(run_part2(line).ok_or("No start-of-message marker")?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    input
+        .lines()
+        .next()
+        .map(str::to_string)
+        .ok_or_else(|| ParseError::at(input, input, "No line found in input"))
+}
+
+pub fn run_part1(line: &str) -> Option<usize> {
+    run(line, 4)
+}
+
+pub fn run_part2(line: &str) -> Option<usize> {
+    run(line, 14)
+}
+
+/// The number of characters up to the end of the first marker, if any.
+fn run(line: &str, marker_length: usize) -> Option<usize> {
+    line
+        .as_bytes()
+        .windows(marker_length)
+        .enumerate()
+        .filter_map(|(i, window)| {
+            let set: HashSet<&u8, RandomState> = HashSet::from_iter(window);
+            if set.len() == marker_length {
+                Some(i + marker_length)
+            } else {
+                None
+            }
+        })
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST_1: &str = include_str!("../input_test_1.txt");
+    const INPUT_TEST_2: &str = include_str!("../input_test_2.txt");
+    const INPUT_TEST_3: &str = include_str!("../input_test_3.txt");
+    const INPUT_TEST_4: &str = include_str!("../input_test_4.txt");
+    const INPUT_TEST_5: &str = include_str!("../input_test_5.txt");
+
+    #[test]
+    fn test_input_1_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST_1).unwrap()), Some(7));
+    }
+
+    #[test]
+    fn test_input_2_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST_2).unwrap()), Some(5));
+    }
+
+    #[test]
+    fn test_input_3_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST_3).unwrap()), Some(6));
+    }
+
+    #[test]
+    fn test_input_4_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST_4).unwrap()), Some(10));
+    }
+
+    #[test]
+    fn test_input_5_part1() {
+        assert_eq!(run_part1(&parse(INPUT_TEST_5).unwrap()), Some(11));
+    }
+
+    #[test]
+    fn test_input_1_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST_1).unwrap()), Some(19));
+    }
+
+    #[test]
+    fn test_input_2_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST_2).unwrap()), Some(23));
+    }
+
+    #[test]
+    fn test_input_3_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST_3).unwrap()), Some(23));
+    }
+
+    #[test]
+    fn test_input_4_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST_4).unwrap()), Some(29));
+    }
+
+    #[test]
+    fn test_input_5_part2() {
+        assert_eq!(run_part2(&parse(INPUT_TEST_5).unwrap()), Some(26));
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(run_part1("abcabc"), None);
+        assert_eq!(run_part1("abc"), None);
+    }
+}
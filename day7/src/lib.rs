@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use common::{ParseError, PartResult, Solution};
+
+pub type Path = Vec<String>;
+pub type Fs = HashMap<Path, usize>;
+
+const TOTAL_DISK_SPACE: usize = 70000000;
+const SPACE_NEEDED: usize = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Fs;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        build_filesystem(input)
+    }
+
+    fn part1(&self, fs: &Self::Input) -> PartResult {
+        Ok(run_part1(fs).into())
+    }
+
+    fn part2(&self, fs: &Self::Input) -> PartResult {
+        Ok(run_part2(fs)
+            .ok_or("The root directory is missing or larger than the disk")?
+            .into())
+    }
+}
+
+pub fn run_part1(fs: &Fs) -> usize {
+    fs.values().filter(|&size| *size <= 100000).sum()
+}
+
+/// `None` if there is no root directory or it does not fit on the disk.
+pub fn run_part2(fs: &Fs) -> Option<usize> {
+    let used_disk_space = fs.get(&vec!["/".to_string()])?;
+    let free_disk_space = TOTAL_DISK_SPACE.checked_sub(*used_disk_space)?;
+    let space_to_delete = SPACE_NEEDED.saturating_sub(free_disk_space);
+    let mut large_enough_dir_sizes: Vec<_> = fs.values().filter(|&s| *s >= space_to_delete).collect();
+    large_enough_dir_sizes.sort();
+    large_enough_dir_sizes.first().copied().copied()
+}
+
+pub fn build_filesystem(input: &str) -> Result<Fs, ParseError> {
+    let mut cwd: Path = Vec::new();
+    let mut fs: Fs = HashMap::new();
+
+    for cmd in input.lines() {
+        match cmd {
+            cd if cd.starts_with("$ cd ") => {
+                let dir = &cd[5..];
+                if dir == ".." {
+                    cwd.pop();
+                } else {
+                    cwd.push(dir.to_string());
+                }
+            }
+            file if file.starts_with(|c: char| c.is_ascii_digit()) => {
+                let size = file.split(' ').next().unwrap();
+                let size = size
+                    .parse::<usize>()
+                    .map_err(|_| ParseError::at(input, size, "Invalid file size"))?;
+                let entry = fs.entry(cwd.clone());
+
+                // update size of current folder
+                entry.and_modify(|s| *s += size).or_insert(size);
+
+                // update size of parent folders
+                for i in 1..cwd.len() {
+                    fs.entry(Vec::from(&cwd[0..cwd.len() - i]))
+                        .and_modify(|s| *s += size)
+                        .or_insert(size);
+                }
+            }
+            "$ ls" => (),
+            dir if dir.starts_with("dir ") => (),
+            _ => return Err(ParseError::at(input, cmd, "Unknown command or output")),
+        }
+    }
+
+    Ok(fs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn test_input_part1() {
+        let fs = build_filesystem(INPUT_TEST).unwrap();
+        assert_eq!(run_part1(&fs), 95437);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        let fs = build_filesystem(INPUT_TEST).unwrap();
+        assert_eq!(run_part2(&fs), Some(24933642));
+    }
+
+    #[test]
+    fn test_no_root() {
+        let fs = build_filesystem("$ cd a\n$ ls\n100 b.txt\n").unwrap();
+        assert_eq!(run_part2(&fs), None);
+    }
+
+    #[test]
+    fn test_root_too_large() {
+        let fs = build_filesystem("$ cd /\n$ ls\n70000001 a.txt\n").unwrap();
+        assert_eq!(run_part2(&fs), None);
+    }
+}
@@ -0,0 +1,265 @@
+use common::{ParseError, PartResult, Solution};
+use grid::Grid;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> PartResult {
+        Ok(run_part1(grid).into())
+    }
+
+    fn part2(&self, grid: &Self::Input) -> PartResult {
+        Ok(run_part2(grid).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|height| height as u8))
+}
+
+pub fn run_part1(grid: &Grid<u8>) -> usize {
+    let visible_trees = visible_trees(grid);
+    count_trues(visible_trees.iter().flatten())
+}
+
+pub fn run_part2(grid: &Grid<u8>) -> usize {
+    let scenic_scores = scenic_score(grid);
+    *scenic_scores.iter().flatten().max().unwrap()
+}
+
+fn visible_trees_for_row(grid: &Grid<u8>, row_ix: usize) -> Vec<bool> {
+    let row = grid.row(row_ix as i64);
+    let visible_from_left = visible_trees_from_start(row.iter());
+    let mut visible_from_right: Vec<_> = visible_trees_from_start(row.iter().rev());
+    visible_from_right.reverse();
+    vec_or(visible_from_left, visible_from_right)
+}
+
+fn visible_trees_for_col(grid: &Grid<u8>, col_ix: usize) -> Vec<bool> {
+    let visible_from_top = visible_trees_from_start(grid.col(col_ix as i64));
+    let mut visible_from_bottom: Vec<_> = visible_trees_from_start(grid.col(col_ix as i64).rev());
+    visible_from_bottom.reverse();
+    vec_or(visible_from_top, visible_from_bottom)
+}
+
+fn visible_trees(grid: &Grid<u8>) -> Vec<Vec<bool>> {
+    let visible_by_row: Vec<_> = (0..grid.height())
+        .map(|row| visible_trees_for_row(grid, row))
+        .collect();
+    let visible_by_col: Vec<_> = (0..grid.width())
+        .map(|col| visible_trees_for_col(grid, col))
+        .collect();
+    let mut result = Vec::with_capacity(grid.height());
+    for row in 0..grid.height() {
+        let mut result_row = Vec::with_capacity(grid.width());
+        for col in 0..grid.width() {
+            result_row.push(visible_by_row[row][col] || visible_by_col[col][row]);
+        }
+        result.push(result_row);
+    }
+    result
+}
+
+fn scenic_score(grid: &Grid<u8>) -> Vec<Vec<usize>> {
+    let mut result = Vec::with_capacity(grid.height());
+    for row in 1..grid.height() - 1 {
+        let mut result_row = Vec::with_capacity(grid.width());
+        for col in 1..grid.width() - 1 {
+            result_row.push(scenic_score_for_tree(grid, row, col));
+        }
+        result.push(result_row);
+    }
+    result
+}
+
+fn height(grid: &Grid<u8>, row_ix: usize, col_ix: usize) -> u8 {
+    grid[(col_ix as i64, row_ix as i64)]
+}
+
+fn scenic_score_for_tree(grid: &Grid<u8>, row_ix: usize, col_ix: usize) -> usize {
+    scenic_score_left(grid, row_ix, col_ix)
+        * scenic_score_right(grid, row_ix, col_ix)
+        * scenic_score_top(grid, row_ix, col_ix)
+        * scenic_score_bottom(grid, row_ix, col_ix)
+}
+
+fn scenic_score_left(grid: &Grid<u8>, row_ix: usize, col_ix: usize) -> usize {
+    let height = height(grid, row_ix, col_ix);
+    grid.row(row_ix as i64)[..col_ix]
+        .iter()
+        .rev()
+        .position(|&x| x >= height)
+        .map(|x| x + 1)
+        .unwrap_or(col_ix)
+}
+
+fn scenic_score_right(grid: &Grid<u8>, row_ix: usize, col_ix: usize) -> usize {
+    let height = height(grid, row_ix, col_ix);
+    grid.row(row_ix as i64)[col_ix + 1..]
+        .iter()
+        .position(|&x| x >= height)
+        .map(|x| x + 1)
+        .unwrap_or(grid.width() - col_ix - 1)
+}
+
+fn scenic_score_top(grid: &Grid<u8>, row_ix: usize, col_ix: usize) -> usize {
+    let height = height(grid, row_ix, col_ix);
+    grid.col(col_ix as i64)
+        .rev()
+        .skip(grid.height() - row_ix)
+        .position(|&x| x >= height)
+        .map(|x| x + 1)
+        .unwrap_or(row_ix)
+}
+
+fn scenic_score_bottom(grid: &Grid<u8>, row_ix: usize, col_ix: usize) -> usize {
+    let height = height(grid, row_ix, col_ix);
+    grid.col(col_ix as i64)
+        .skip(row_ix + 1)
+        .position(|&x| x >= height)
+        .map(|x| x + 1)
+        .unwrap_or(grid.height() - row_ix - 1)
+}
+
+fn visible_trees_from_start<'a, T>(r: T) -> Vec<bool>
+where
+    T: Iterator<Item = &'a u8>,
+{
+    let mut max_height = None;
+    let mut visible_trees = Vec::new();
+    for &height in r {
+        if max_height < Some(height) {
+            visible_trees.push(true);
+            max_height = Some(height);
+        } else {
+            visible_trees.push(false);
+        }
+    }
+    visible_trees
+}
+
+fn vec_or(a: Vec<bool>, b: Vec<bool>) -> Vec<bool> {
+    a.iter().zip(b.iter()).map(|(&l, &r)| l || r).collect()
+}
+
+fn count_trues<'a>(bools: impl Iterator<Item = &'a bool>) -> usize {
+    bools.filter(|&x| *x).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    fn grid() -> Grid<u8> {
+        parse(INPUT_TEST).unwrap()
+    }
+
+    #[test]
+    fn test_visible_trees_from_start() {
+        let visible_trees = visible_trees_from_start([3, 0, 3, 7, 3].iter());
+        println!("{:#?}", visible_trees);
+        assert_eq!(visible_trees, vec![true, false, false, true, false]);
+        assert_eq!(visible_trees_from_start([0, 0].iter()), vec![true, false]);
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!(grid.width(), 5);
+        assert_eq!(grid.height(), 5);
+    }
+
+    #[test]
+    fn test_iterate_row() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &[2, 5, 5, 1, 2])
+    }
+
+    #[test]
+    fn test_iterate_row_reverse() {
+        let grid = grid();
+        let row3_reverse: Vec<_> = grid.row(2).iter().rev().copied().collect();
+        assert_eq!(row3_reverse, vec![2, 3, 3, 5, 6])
+    }
+
+    #[test]
+    fn test_iterate_col() {
+        let grid = grid();
+        let col2: Vec<_> = grid.col(1).copied().collect();
+        assert_eq!(col2, vec![0, 5, 5, 3, 5])
+    }
+
+    #[test]
+    fn test_iterate_col_reverse() {
+        let grid = grid();
+        let col3_reverse: Vec<_> = grid.col(2).rev().copied().collect();
+        assert_eq!(col3_reverse, vec![3, 5, 3, 5, 3])
+    }
+
+    #[test]
+    fn test_iterate_visible_trees_row() {
+        let row3_visible = visible_trees_for_row(&grid(), 2);
+        assert_eq!(row3_visible, vec![true, true, false, true, true])
+    }
+
+    #[test]
+    fn test_iterate_visible_trees_col() {
+        let col5_visible = visible_trees_for_col(&grid(), 4);
+        assert_eq!(col5_visible, vec![true, false, false, true, true])
+    }
+
+    #[test]
+    fn test_scenic_score_top() {
+        let grid = grid();
+        assert_eq!(scenic_score_top(&grid, 1, 2), 1);
+        assert_eq!(scenic_score_top(&grid, 3, 2), 2);
+    }
+
+    #[test]
+    fn test_scenic_score_left() {
+        let grid = grid();
+        assert_eq!(scenic_score_left(&grid, 1, 2), 1);
+        assert_eq!(scenic_score_left(&grid, 3, 2), 2);
+    }
+
+    #[test]
+    fn test_scenic_score_bottom() {
+        let grid = grid();
+        assert_eq!(scenic_score_bottom(&grid, 1, 2), 2);
+        assert_eq!(scenic_score_bottom(&grid, 3, 2), 1);
+    }
+
+    #[test]
+    fn test_scenic_score_right() {
+        let grid = grid();
+        assert_eq!(scenic_score_right(&grid, 1, 2), 2);
+        assert_eq!(scenic_score_right(&grid, 3, 2), 2);
+    }
+
+    #[test]
+    fn test_input_part1() {
+        assert_eq!(run_part1(&grid()), 21);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("303\n25a\n653").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "a"));
+        assert_eq!(parse("303\n25\n653").err().unwrap().line, 2);
+    }
+
+    #[test]
+    fn test_input_part2() {
+        let grid = grid();
+        eprintln!("{:#?}", scenic_score(&grid));
+        assert_eq!(run_part2(&grid), 8);
+    }
+}
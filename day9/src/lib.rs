@@ -1,27 +1,38 @@
 use std::{cmp, collections::HashSet};
 
-const INPUT: &str = include_str!("../input.txt");
+use common::{parse_lines, ParseError, PartResult, Solution};
 
 #[derive(Clone, Debug)]
-enum Move {
+pub enum Move {
     L,
     R,
     U,
     D,
 }
 
-fn main() {
-    let moves = parse_moves(INPUT);
-    let result_part1 = run_part1(&moves);
-    let result_part2 = run_part2(&moves);
-    println!("Part 1: {result_part1} - Part 2: {result_part2}")
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_moves(input)
+    }
+
+    fn part1(&self, moves: &Self::Input) -> PartResult {
+        Ok(run_part1(moves).into())
+    }
+
+    fn part2(&self, moves: &Self::Input) -> PartResult {
+        Ok(run_part2(moves).into())
+    }
 }
 
-fn run_part1(moves: &Vec<Move>) -> usize {
+pub fn run_part1(moves: &Vec<Move>) -> usize {
     run(moves, 2)
 }
 
-fn run_part2(moves: &Vec<Move>) -> usize {
+pub fn run_part2(moves: &Vec<Move>) -> usize {
     run(moves, 10)
 }
 
@@ -58,25 +69,29 @@ fn move_tail(pos_tail: (i32, i32), pos_head: (i32, i32)) -> (i32, i32) {
     }
 }
 
-fn parse_moves(input: &str) -> Vec<Move> {
-    input.lines().flat_map(parse_moves_line).collect()
+pub fn parse_moves(input: &str) -> Result<Vec<Move>, ParseError> {
+    Ok(parse_lines(input, parse_moves_line)?.concat())
 }
 
-fn parse_moves_line(line: &str) -> Vec<Move> {
-    let mut split = line.split(' ');
-    let direction = split.next().unwrap();
-    let count_str = split.next().unwrap();
-    let count = count_str.parse().unwrap();
-    vec![parse_move(direction); count]
+fn parse_moves_line(line: &str) -> Result<Vec<Move>, ParseError> {
+    let (direction, count) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::at(line, line, "Expected a direction and a count"))?;
+    let count = count
+        .parse()
+        .map_err(|_| ParseError::at(line, count, "Invalid count"))?;
+    let m = parse_move(direction)
+        .ok_or_else(|| ParseError::at(line, direction, "Invalid direction"))?;
+    Ok(vec![m; count])
 }
 
-fn parse_move(direction: &str) -> Move {
+fn parse_move(direction: &str) -> Option<Move> {
     match direction {
-        "L" => Move::L,
-        "R" => Move::R,
-        "U" => Move::U,
-        "D" => Move::D,
-        _ => panic!("Invalid direction"),
+        "L" => Some(Move::L),
+        "R" => Some(Move::R),
+        "U" => Some(Move::U),
+        "D" => Some(Move::D),
+        _ => None,
     }
 }
 
@@ -89,19 +104,25 @@ mod tests {
 
     #[test]
     fn test_input_part1() {
-        let moves = parse_moves(INPUT_TEST);
+        let moves = parse_moves(INPUT_TEST).unwrap();
         assert_eq!(run_part1(&moves), 13);
     }
 
     #[test]
     fn test_input_part2() {
-        let moves = parse_moves(INPUT_TEST);
+        let moves = parse_moves(INPUT_TEST).unwrap();
         assert_eq!(run_part2(&moves), 1);
     }
 
     #[test]
     fn test_input_part2_2() {
-        let moves = parse_moves(INPUT_TEST_2);
+        let moves = parse_moves(INPUT_TEST_2).unwrap();
         assert_eq!(run_part2(&moves), 36);
     }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_moves("R 4\nX 4").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "X"));
+    }
 }
@@ -0,0 +1,350 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut, Range},
+};
+
+use common::ParseError;
+
+/// A position `(x, y)`, with `y` growing downwards.
+pub type Pos = (i64, i64);
+
+/// Right, down, left, up.
+pub const NEIGHBOURS4: [Pos; 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+/// [`NEIGHBOURS4`] plus the diagonals.
+pub const NEIGHBOURS8: [Pos; 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+/// A rectangular grid stored row by row. The top-left cell is at `origin`,
+/// which does not have to be `(0, 0)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    origin: Pos,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self::with_origin((0, 0), width, height, fill)
+    }
+
+    pub fn with_origin(origin: Pos, width: usize, height: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+            origin,
+        }
+    }
+
+    /// Grows the grid so that it contains `pos`, filling new cells with `fill`.
+    /// Growing downwards only appends rows, so it is cheap.
+    pub fn grow_to_include(&mut self, pos: Pos, fill: T) {
+        if self.contains(pos) {
+            return;
+        }
+        let (xs, ys) = (self.x_range(), self.y_range());
+        let xs = xs.start.min(pos.0)..xs.end.max(pos.0 + 1);
+        let ys = ys.start.min(pos.1)..ys.end.max(pos.1 + 1);
+
+        if xs == self.x_range() && ys.start == self.origin.1 {
+            self.height = (ys.end - ys.start) as usize;
+            self.cells.resize(self.width * self.height, fill);
+            return;
+        }
+
+        let mut grown = Grid::with_origin(
+            (xs.start, ys.start),
+            (xs.end - xs.start) as usize,
+            (ys.end - ys.start) as usize,
+            fill,
+        );
+        for (pos, cell) in self.iter() {
+            grown[pos] = cell.clone();
+        }
+        *self = grown;
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character and one row per line. The top-left cell
+    /// is at `(0, 0)`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::at(input, input, "Empty grid"));
+        }
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for line in input.lines() {
+            for (ix, c) in line.char_indices() {
+                let span = &line[ix..ix + c.len_utf8()];
+                let value =
+                    cell(c).ok_or_else(|| ParseError::at(input, span, "Unexpected character"))?;
+                cells.push(value);
+            }
+            height += 1;
+            if cells.len() != width * height {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("Expected {width} columns"),
+                ));
+            }
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+            origin: (0, 0),
+        })
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+            origin: (0, 0),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> Pos {
+        self.origin
+    }
+
+    pub fn x_range(&self) -> Range<i64> {
+        self.origin.0..self.origin.0 + self.width as i64
+    }
+
+    pub fn y_range(&self) -> Range<i64> {
+        self.origin.1..self.origin.1 + self.height as i64
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.x_range().contains(&pos.0) && self.y_range().contains(&pos.1)
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos).then(|| {
+            let x = (pos.0 - self.origin.0) as usize;
+            let y = (pos.1 - self.origin.1) as usize;
+            y * self.width + x
+        })
+    }
+
+    fn pos_of(&self, index: usize) -> Pos {
+        (
+            self.origin.0 + (index % self.width) as i64,
+            self.origin.1 + (index / self.width) as i64,
+        )
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|ix| &self.cells[ix])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|ix| &mut self.cells[ix])
+    }
+
+    /// The cells of row `y`, from left to right.
+    pub fn row(&self, y: i64) -> &[T] {
+        assert!(self.y_range().contains(&y), "Row {y} out of the grid");
+        let start = (y - self.origin.1) as usize * self.width;
+        &self.cells[start..start + self.width]
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn col(&self, x: i64) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(self.x_range().contains(&x), "Column {x} out of the grid");
+        let start = (x - self.origin.0) as usize;
+        self.cells[start..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let xs = self.x_range();
+        self.y_range()
+            .flat_map(move |y| xs.clone().map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, row by row, that satisfies `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|ix| self.pos_of(ix))
+    }
+
+    /// The orthogonal neighbours of `pos` that lie inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &NEIGHBOURS4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that lie inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &NEIGHBOURS8)
+    }
+
+    fn neighbours<'a>(&'a self, pos: Pos, steps: &'a [Pos]) -> impl Iterator<Item = Pos> + 'a {
+        steps
+            .iter()
+            .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+            .filter(|&p| self.contains(p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            origin: self.origin,
+        }
+    }
+
+    /// Draws the grid with one character per cell and one line per row.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} out of the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} out of the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "123\n456";
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits(INPUT);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Grid::parse("123\n4x6", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        let err = Grid::parse("123\n45", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let grid = digits(INPUT);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.col(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.col(2).rev().copied().collect::<Vec<_>>(), vec![6, 3]);
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits(INPUT);
+        let mut n4: Vec<_> = grid.neighbours4((0, 0)).collect();
+        n4.sort();
+        assert_eq!(n4, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_origin() {
+        let mut grid = Grid::with_origin((10, -2), 2, 2, '.');
+        grid[(11, -1)] = '#';
+        assert!(grid.contains((10, -2)));
+        assert!(!grid.contains((0, 0)));
+        assert_eq!(grid.position(|&c| c == '#'), Some((11, -1)));
+        assert_eq!(grid.to_string(), "..\n.#");
+    }
+
+    #[test]
+    fn test_grow_to_include() {
+        let mut grid = digits(INPUT);
+        grid.grow_to_include((1, 3), 0);
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        grid.grow_to_include((-1, -1), 0);
+        assert_eq!(grid.origin(), (-1, -1));
+        assert_eq!(grid.to_string(), "0000\n0123\n0456\n0000\n0000");
+    }
+
+    #[test]
+    fn test_grow_empty() {
+        let mut grid = Grid::new(7, 0, false);
+        grid.grow_to_include((3, 2), false);
+        grid[(3, 2)] = true;
+        assert_eq!((grid.width(), grid.height()), (7, 3));
+        assert_eq!(
+            grid.render(|&b| if b { '#' } else { '.' }),
+            ".......\n.......\n...#..."
+        );
+    }
+}